@@ -1,14 +1,19 @@
 #[cfg(all(target_os = "macos", target_arch = "aarch64"))]
 use crate::apple_intelligence;
 use crate::audio_feedback::{play_feedback_sound, play_feedback_sound_blocking, SoundType};
+use crate::audio_toolkit::constants::WHISPER_SAMPLE_RATE;
 use crate::managers::audio::AudioRecordingManager;
 use crate::managers::history::HistoryManager;
 use crate::managers::transcription::TranscriptionManager;
+use crate::metrics::{as_ms, elapsed_ms, LatencyMetrics, LatencyTracker};
+use crate::pipeline::{self, PipelineStage};
+use crate::send_to::send_to_auto_connectors;
 use crate::settings::{get_settings, AppSettings, APPLE_INTELLIGENCE_PROVIDER_ID};
 use crate::shortcut;
 use crate::stt_client::transcribe_with_stt_api;
 use crate::tray::{change_tray_icon, TrayIconState};
 use crate::utils::{self, show_recording_overlay, show_transcribing_overlay};
+use crate::worker_pool;
 use crate::ManagedToggleState;
 use anyhow;
 use ferrous_opencc::{config::BuiltinConfig, OpenCC};
@@ -193,9 +198,15 @@ async fn maybe_convert_chinese_variant(
         BuiltinConfig::S2twp
     };
 
-    match OpenCC::from_config(config) {
-        Ok(converter) => {
-            let converted = converter.convert(transcription);
+    // Loading the dictionaries and converting are CPU-bound, so keep them off the async runtime
+    let input = transcription.to_string();
+    let result = worker_pool::run(move || {
+        OpenCC::from_config(config).map(|converter| converter.convert(&input))
+    })
+    .await;
+
+    match result {
+        Ok(Ok(converted)) => {
             debug!(
                 "OpenCC translation completed. Input length: {}, Output length: {}",
                 transcription.len(),
@@ -203,10 +214,17 @@ async fn maybe_convert_chinese_variant(
             );
             Some(converted)
         }
-        Err(e) => {
+        Ok(Err(e)) => {
             error!("Failed to initialize OpenCC converter: {}. Falling back to original transcription.", e);
             None
         }
+        Err(e) => {
+            error!(
+                "Chinese variant conversion failed: {}. Falling back to original transcription.",
+                e
+            );
+            None
+        }
     }
 }
 
@@ -270,6 +288,7 @@ impl ShortcutAction for TranscribeAction {
         }
 
         if recording_started {
+            pipeline::set_stage(PipelineStage::Recording);
             // Dynamically register the cancel shortcut in a separate task to avoid deadlock
             shortcut::register_cancel_shortcut(app);
         }
@@ -319,15 +338,22 @@ impl ShortcutAction for TranscribeAction {
                     samples.len()
                 );
 
+                pipeline::set_stage(PipelineStage::Transcribing);
                 let transcription_time = Instant::now();
-                let samples_clone = samples.clone(); // Clone for history saving
+                // Shared by the STT upload and the history save, so the upload doesn't
+                // need a copy of its own
+                let samples: Arc<[f32]> = samples.into();
+                let mut metrics = LatencyMetrics {
+                    capture_ms: samples.len() as u64 * 1000 / WHISPER_SAMPLE_RATE as u64,
+                    ..Default::default()
+                };
 
                 // Check if we should use STT API
                 let settings = get_settings(&ah);
                 let stt_api_enabled = settings.stt_api.enabled;
                 let transcription_result = if stt_api_enabled {
                     debug!("Using STT API for transcription (from actions)");
-                    match transcribe_with_stt_api(&ah, samples).await {
+                    match transcribe_with_stt_api(&ah, Arc::clone(&samples), &mut metrics).await {
                         Ok(text) => Ok(text),
                         Err(e) => {
                             error!("STT API transcription failed: {}", e);
@@ -335,7 +361,18 @@ impl ShortcutAction for TranscribeAction {
                         }
                     }
                 } else {
-                    tm.transcribe(samples)
+                    // Inference blocks for seconds on long recordings, so keep it off the
+                    // async runtime. The engine takes ownership of its input, so it gets
+                    // a copy.
+                    let tm = Arc::clone(&tm);
+                    let audio = samples.to_vec();
+                    let result = tauri::async_runtime::spawn_blocking(move || tm.transcribe(audio))
+                        .await
+                        .unwrap_or_else(|e| {
+                            Err(anyhow::anyhow!("Transcription task failed: {}", e))
+                        });
+                    metrics.inference_ms = elapsed_ms(transcription_time);
+                    result
                 };
 
                 match transcription_result {
@@ -350,6 +387,8 @@ impl ShortcutAction for TranscribeAction {
                             let mut final_text = transcription.clone();
                             let mut post_processed_text: Option<String> = None;
                             let mut post_process_prompt: Option<String> = None;
+                            pipeline::set_stage(PipelineStage::PostProcessing);
+                            let post_process_time = Instant::now();
 
                             // First, check if Chinese variant conversion is needed
                             if let Some(converted_text) =
@@ -383,37 +422,42 @@ impl ShortcutAction for TranscribeAction {
                                 // Chinese conversion was applied but no LLM post-processing
                                 post_processed_text = Some(final_text.clone());
                             }
+                            if post_processed_text.is_some() {
+                                metrics.post_processing_ms = Some(elapsed_ms(post_process_time));
+                            }
 
-                            // Save to history with post-processed text and prompt
-                            let hm_clone = Arc::clone(&hm);
-                            let transcription_for_history = transcription.clone();
-                            tauri::async_runtime::spawn(async move {
-                                if let Err(e) = hm_clone
-                                    .save_transcription(
-                                        samples_clone,
-                                        transcription_for_history,
-                                        post_processed_text,
-                                        post_process_prompt,
-                                    )
-                                    .await
-                                {
-                                    error!("Failed to save transcription to history: {}", e);
-                                }
-                            });
+                            // Forward to any connectors configured to receive every transcript
+                            send_to_auto_connectors(&ah, &final_text);
 
                             // Paste the final text (either processed or original)
+                            pipeline::set_stage(PipelineStage::Pasting);
                             let ah_clone = ah.clone();
-                            let paste_time = Instant::now();
+                            let (paste_done_tx, paste_done_rx) = tokio::sync::oneshot::channel();
                             ah.run_on_main_thread(move || {
-                                match utils::paste(final_text, ah_clone.clone()) {
-                                    Ok(()) => debug!(
-                                        "Text pasted successfully in {:?}",
-                                        paste_time.elapsed()
-                                    ),
-                                    Err(e) => error!("Failed to paste transcription: {}", e),
+                                let paste_time = Instant::now();
+                                let blocked = match utils::paste(final_text, ah_clone.clone()) {
+                                    Ok(None) => {
+                                        debug!(
+                                            "Text pasted successfully in {:?}",
+                                            paste_time.elapsed()
+                                        );
+                                        false
+                                    }
+                                    // Already logged; the text is on the clipboard
+                                    Ok(Some(_)) => true,
+                                    Err(e) => {
+                                        error!("Failed to paste transcription: {}", e);
+                                        false
+                                    }
+                                };
+                                let _ = paste_done_tx.send(paste_time.elapsed());
+                                // Hide the overlay after transcription is complete, or keep
+                                // it up to say the text was copied instead of pasted
+                                if blocked {
+                                    utils::show_paste_blocked_overlay(&ah_clone);
+                                } else {
+                                    utils::hide_recording_overlay(&ah_clone);
                                 }
-                                // Hide the overlay after transcription is complete
-                                utils::hide_recording_overlay(&ah_clone);
                                 change_tray_icon(&ah_clone, TrayIconState::Idle);
                             })
                             .unwrap_or_else(|e| {
@@ -421,6 +465,30 @@ impl ShortcutAction for TranscribeAction {
                                 utils::hide_recording_overlay(&ah);
                                 change_tray_icon(&ah, TrayIconState::Idle);
                             });
+
+                            // Save to history with post-processed text, prompt and timings once
+                            // the paste has finished, without holding up the next recording
+                            let ah_clone = ah.clone();
+                            tauri::async_runtime::spawn(async move {
+                                // The sender is dropped without sending if the paste never ran
+                                metrics.insertion_ms = paste_done_rx.await.ok().map(as_ms);
+                                metrics.total_ms = elapsed_ms(stop_recording_time);
+                                debug!("Pipeline latency: {:?}", metrics);
+                                ah_clone.state::<LatencyTracker>().record(metrics.clone());
+
+                                if let Err(e) = hm
+                                    .save_transcription(
+                                        samples,
+                                        transcription,
+                                        post_processed_text,
+                                        post_process_prompt,
+                                        Some(metrics),
+                                    )
+                                    .await
+                                {
+                                    error!("Failed to save transcription to history: {}", e);
+                                }
+                            });
                         } else {
                             utils::hide_recording_overlay(&ah);
                             change_tray_icon(&ah, TrayIconState::Idle);
@@ -438,6 +506,8 @@ impl ShortcutAction for TranscribeAction {
                 change_tray_icon(&ah, TrayIconState::Idle);
             }
 
+            pipeline::set_stage(PipelineStage::Idle);
+
             // Clear toggle state now that transcription is complete
             if let Ok(mut states) = ah.state::<ManagedToggleState>().lock() {
                 states.active_toggles.insert(binding_id, false);
@@ -0,0 +1,114 @@
+//! Command-line argument handling
+//!
+//! Handy is normally launched without arguments. The flags parsed here only
+//! change how the app starts up; everything else is driven by settings.
+
+use crate::settings::AppSettings;
+use tauri::{AppHandle, Manager};
+
+/// Argument added to the launch-at-login entry so a login launch can be told apart
+/// from the user opening the app.
+pub const AUTOSTART_ARG: &str = "--autostart";
+
+/// Argument followed by the pid of an instance this launch replaces, used when
+/// restarting as Administrator on Windows.
+pub const REPLACES_PID_ARG: &str = "--replaces-pid";
+
+/// Flags passed on the command line when launching Handy.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CliArgs {
+    /// Run without creating the main webview window. The tray, shortcuts,
+    /// and audio pipeline are still initialized.
+    pub headless: bool,
+    /// Launched by the OS at login (the autostart entry passes `--autostart`).
+    pub autostarted: bool,
+    /// Pid of the instance that started this one and is about to exit.
+    pub replaces_pid: Option<u32>,
+}
+
+impl CliArgs {
+    /// Parse flags from the process arguments.
+    pub fn from_env() -> Self {
+        Self::parse(std::env::args().skip(1))
+    }
+
+    /// Parse flags from an iterator of arguments (excluding the program name).
+    /// Unknown arguments are ignored so that platform launchers can pass their own.
+    pub fn parse<I, S>(args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut cli = Self::default();
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            match arg.as_ref() {
+                "--headless" => cli.headless = true,
+                AUTOSTART_ARG => cli.autostarted = true,
+                REPLACES_PID_ARG => {
+                    cli.replaces_pid = args.next().and_then(|pid| pid.as_ref().parse().ok());
+                }
+                _ => {}
+            }
+        }
+        cli
+    }
+}
+
+/// Whether the app was launched with `--headless`.
+pub fn is_headless(app: &AppHandle) -> bool {
+    app.try_state::<CliArgs>()
+        .map(|cli| cli.headless)
+        .unwrap_or(false)
+}
+
+/// Whether the app should start without showing the main window, either because
+/// the user always starts hidden or because this is a login launch and they asked
+/// for those to go straight to the tray.
+pub fn should_start_hidden(app: &AppHandle, settings: &AppSettings) -> bool {
+    let cli = app
+        .try_state::<CliArgs>()
+        .map(|cli| *cli)
+        .unwrap_or_default();
+    settings.start_hidden || (cli.autostarted && settings.autostart_start_hidden)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CliArgs;
+
+    #[test]
+    fn defaults_to_windowed() {
+        let cli = CliArgs::parse(Vec::<String>::new());
+        assert!(!cli.headless);
+    }
+
+    #[test]
+    fn parses_headless_flag() {
+        let cli = CliArgs::parse(["--headless"]);
+        assert!(cli.headless);
+    }
+
+    #[test]
+    fn parses_autostart_flag() {
+        let cli = CliArgs::parse(["--autostart"]);
+        assert!(cli.autostarted);
+        assert!(!cli.headless);
+    }
+
+    #[test]
+    fn parses_replaced_pid() {
+        let cli = CliArgs::parse(["--replaces-pid", "4242", "--headless"]);
+        assert_eq!(cli.replaces_pid, Some(4242));
+        assert!(cli.headless);
+
+        let cli = CliArgs::parse(["--replaces-pid", "not-a-pid"]);
+        assert_eq!(cli.replaces_pid, None);
+    }
+
+    #[test]
+    fn ignores_unknown_arguments() {
+        let cli = CliArgs::parse(["-psn_0_12345", "--headless", "--foo"]);
+        assert!(cli.headless);
+    }
+}
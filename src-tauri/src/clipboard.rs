@@ -1,9 +1,10 @@
+use crate::helpers::{elevation, secure_input};
 use crate::input::{self, EnigoState};
-use crate::settings::{get_settings, ClipboardHandling, PasteMethod};
+use crate::settings::{get_settings, ClipboardHandling, PasteMethod, TypingTool};
 use enigo::Enigo;
-use log::info;
+use log::{info, warn};
 use std::time::Duration;
-use tauri::{AppHandle, Manager};
+use tauri::{AppHandle, Emitter, Manager};
 use tauri_plugin_clipboard_manager::ClipboardExt;
 
 #[cfg(target_os = "linux")]
@@ -18,6 +19,7 @@ fn paste_via_clipboard(
     app_handle: &AppHandle,
     paste_method: &PasteMethod,
     paste_delay_ms: u64,
+    typing_tool: TypingTool,
 ) -> Result<(), String> {
     let clipboard = app_handle.clipboard();
     let clipboard_content = clipboard.read_text().unwrap_or_default();
@@ -45,10 +47,13 @@ fn paste_via_clipboard(
 
     // Send paste key combo
     #[cfg(target_os = "linux")]
-    let key_combo_sent = try_send_key_combo_linux(paste_method)?;
+    let key_combo_sent = try_send_key_combo_linux(paste_method, typing_tool)?;
 
     #[cfg(not(target_os = "linux"))]
-    let key_combo_sent = false;
+    let key_combo_sent = {
+        let _ = typing_tool;
+        false
+    };
 
     // Fall back to enigo if no native tool handled it
     if !key_combo_sent {
@@ -77,88 +82,123 @@ fn paste_via_clipboard(
     Ok(())
 }
 
-/// Attempts to send a key combination using Linux-native tools.
-/// Returns `Ok(true)` if a native tool handled it, `Ok(false)` to fall back to enigo.
+/// Picks the Linux-native tool used for input injection. A tool chosen in settings
+/// wins when it's installed; otherwise one is picked based on the session type.
+/// Returns `None` to fall back to enigo.
 #[cfg(target_os = "linux")]
-fn try_send_key_combo_linux(paste_method: &PasteMethod) -> Result<bool, String> {
+fn select_typing_tool(preferred: TypingTool, direct_text: bool) -> Option<TypingTool> {
+    match preferred {
+        TypingTool::Enigo => return None,
+        TypingTool::Auto => {}
+        // kwtype only types text, it can't send key combos
+        TypingTool::Kwtype if !direct_text => {}
+        tool if is_typing_tool_available(tool) => return Some(tool),
+        tool => warn!(
+            "Typing tool {:?} is not installed, selecting one automatically",
+            tool
+        ),
+    }
+
     if is_wayland() {
-        // Wayland: prefer wtype (but not on KDE), then dotool, then ydotool
+        // KDE Wayland: prefer kwtype (uses KDE Fake Input protocol, supports umlauts)
+        if direct_text && is_kde_wayland() && is_kwtype_available() {
+            return Some(TypingTool::Kwtype);
+        }
+        // Wayland: prefer wtype, then dotool, then ydotool
         // Note: wtype doesn't work on KDE (no zwp_virtual_keyboard_manager_v1 support)
         if !is_kde_wayland() && is_wtype_available() {
-            info!("Using wtype for key combo");
-            send_key_combo_via_wtype(paste_method)?;
-            return Ok(true);
+            return Some(TypingTool::Wtype);
         }
         if is_dotool_available() {
-            info!("Using dotool for key combo");
-            send_key_combo_via_dotool(paste_method)?;
-            return Ok(true);
+            return Some(TypingTool::Dotool);
         }
         if is_ydotool_available() {
-            info!("Using ydotool for key combo");
-            send_key_combo_via_ydotool(paste_method)?;
-            return Ok(true);
+            return Some(TypingTool::Ydotool);
         }
     } else {
         // X11: prefer xdotool, then ydotool
         if is_xdotool_available() {
-            info!("Using xdotool for key combo");
-            send_key_combo_via_xdotool(paste_method)?;
-            return Ok(true);
+            return Some(TypingTool::Xdotool);
         }
         if is_ydotool_available() {
+            return Some(TypingTool::Ydotool);
+        }
+    }
+
+    None
+}
+
+#[cfg(target_os = "linux")]
+fn is_typing_tool_available(tool: TypingTool) -> bool {
+    match tool {
+        TypingTool::Wtype => is_wtype_available(),
+        TypingTool::Kwtype => is_kwtype_available(),
+        TypingTool::Dotool => is_dotool_available(),
+        TypingTool::Ydotool => is_ydotool_available(),
+        TypingTool::Xdotool => is_xdotool_available(),
+        TypingTool::Auto | TypingTool::Enigo => false,
+    }
+}
+
+/// Attempts to send a key combination using Linux-native tools.
+/// Returns `Ok(true)` if a native tool handled it, `Ok(false)` to fall back to enigo.
+#[cfg(target_os = "linux")]
+fn try_send_key_combo_linux(
+    paste_method: &PasteMethod,
+    typing_tool: TypingTool,
+) -> Result<bool, String> {
+    match select_typing_tool(typing_tool, false) {
+        Some(TypingTool::Wtype) => {
+            info!("Using wtype for key combo");
+            send_key_combo_via_wtype(paste_method)?;
+        }
+        Some(TypingTool::Dotool) => {
+            info!("Using dotool for key combo");
+            send_key_combo_via_dotool(paste_method)?;
+        }
+        Some(TypingTool::Ydotool) => {
             info!("Using ydotool for key combo");
             send_key_combo_via_ydotool(paste_method)?;
-            return Ok(true);
         }
+        Some(TypingTool::Xdotool) => {
+            info!("Using xdotool for key combo");
+            send_key_combo_via_xdotool(paste_method)?;
+        }
+        _ => return Ok(false),
     }
 
-    Ok(false)
+    Ok(true)
 }
 
 /// Attempts to type text directly using Linux-native tools.
 /// Returns `Ok(true)` if a native tool handled it, `Ok(false)` to fall back to enigo.
 #[cfg(target_os = "linux")]
-fn try_direct_typing_linux(text: &str) -> Result<bool, String> {
-    if is_wayland() {
-        // KDE Wayland: prefer kwtype (uses KDE Fake Input protocol, supports umlauts)
-        if is_kde_wayland() && is_kwtype_available() {
-            info!("Using kwtype for direct text input on KDE Wayland");
+fn try_direct_typing_linux(text: &str, typing_tool: TypingTool) -> Result<bool, String> {
+    match select_typing_tool(typing_tool, true) {
+        Some(TypingTool::Kwtype) => {
+            info!("Using kwtype for direct text input");
             type_text_via_kwtype(text)?;
-            return Ok(true);
         }
-        // Wayland: prefer wtype, then dotool, then ydotool
-        // Note: wtype doesn't work on KDE (no zwp_virtual_keyboard_manager_v1 support)
-        if !is_kde_wayland() && is_wtype_available() {
+        Some(TypingTool::Wtype) => {
             info!("Using wtype for direct text input");
             type_text_via_wtype(text)?;
-            return Ok(true);
         }
-        if is_dotool_available() {
+        Some(TypingTool::Dotool) => {
             info!("Using dotool for direct text input");
             type_text_via_dotool(text)?;
-            return Ok(true);
         }
-        if is_ydotool_available() {
+        Some(TypingTool::Ydotool) => {
             info!("Using ydotool for direct text input");
             type_text_via_ydotool(text)?;
-            return Ok(true);
         }
-    } else {
-        // X11: prefer xdotool, then ydotool
-        if is_xdotool_available() {
+        Some(TypingTool::Xdotool) => {
             info!("Using xdotool for direct text input");
             type_text_via_xdotool(text)?;
-            return Ok(true);
-        }
-        if is_ydotool_available() {
-            info!("Using ydotool for direct text input");
-            type_text_via_ydotool(text)?;
-            return Ok(true);
         }
+        _ => return Ok(false),
     }
 
-    Ok(false)
+    Ok(true)
 }
 
 /// Check if wtype is available (Wayland text input tool)
@@ -434,19 +474,38 @@ fn send_key_combo_via_xdotool(paste_method: &PasteMethod) -> Result<(), String>
 }
 
 /// Types text directly by simulating individual key presses.
-fn paste_direct(enigo: &mut Enigo, text: &str) -> Result<(), String> {
+fn paste_direct(enigo: &mut Enigo, text: &str, typing_tool: TypingTool) -> Result<(), String> {
     #[cfg(target_os = "linux")]
     {
-        if try_direct_typing_linux(text)? {
+        if try_direct_typing_linux(text, typing_tool)? {
             return Ok(());
         }
         info!("Falling back to enigo for direct text input");
     }
 
+    #[cfg(not(target_os = "linux"))]
+    let _ = typing_tool;
+
     input::paste_text_direct(enigo, text)
 }
 
-pub fn paste(text: String, app_handle: AppHandle) -> Result<(), String> {
+/// Returns why simulated input can't reach the focused app right now, if it can't.
+/// - `elevated_window`: the focused window is elevated and UIPI drops our input (Windows)
+/// - `secure_input`: another app has Secure Event Input enabled (macOS)
+fn paste_blocked_reason() -> Option<&'static str> {
+    if elevation::is_paste_blocked_by_uipi() {
+        return Some("elevated_window");
+    }
+    if secure_input::is_secure_input_enabled() {
+        return Some("secure_input");
+    }
+    None
+}
+
+/// Pastes text into the focused app. Returns the reason from
+/// [`paste_blocked_reason`] when the paste was blocked and the text was left on
+/// the clipboard instead.
+pub fn paste(text: String, app_handle: AppHandle) -> Result<Option<&'static str>, String> {
     let settings = get_settings(&app_handle);
     let paste_method = settings.paste_method;
     let paste_delay_ms = settings.paste_delay_ms;
@@ -463,6 +522,23 @@ pub fn paste(text: String, app_handle: AppHandle) -> Result<(), String> {
         paste_method, paste_delay_ms
     );
 
+    // When simulated input would be silently dropped, leave the text on the
+    // clipboard for the user to paste by hand instead.
+    if paste_method != PasteMethod::None {
+        if let Some(reason) = paste_blocked_reason() {
+            warn!(
+                "Paste blocked ({}); copying transcription to clipboard instead of pasting",
+                reason
+            );
+            app_handle
+                .clipboard()
+                .write_text(&text)
+                .map_err(|e| format!("Failed to copy to clipboard: {}", e))?;
+            let _ = app_handle.emit("paste-blocked", reason);
+            return Ok(Some(reason));
+        }
+    }
+
     // Get the managed Enigo instance
     let enigo_state = app_handle
         .try_state::<EnigoState>()
@@ -478,7 +554,7 @@ pub fn paste(text: String, app_handle: AppHandle) -> Result<(), String> {
             info!("PasteMethod::None selected - skipping paste action");
         }
         PasteMethod::Direct => {
-            paste_direct(&mut enigo, &text)?;
+            paste_direct(&mut enigo, &text, settings.typing_tool)?;
         }
         PasteMethod::CtrlV | PasteMethod::CtrlShiftV | PasteMethod::ShiftInsert => {
             paste_via_clipboard(
@@ -487,6 +563,7 @@ pub fn paste(text: String, app_handle: AppHandle) -> Result<(), String> {
                 &app_handle,
                 &paste_method,
                 paste_delay_ms,
+                settings.typing_tool,
             )?
         }
     }
@@ -499,5 +576,5 @@ pub fn paste(text: String, app_handle: AppHandle) -> Result<(), String> {
             .map_err(|e| format!("Failed to copy to clipboard: {}", e))?;
     }
 
-    Ok(())
+    Ok(None)
 }
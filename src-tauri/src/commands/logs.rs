@@ -0,0 +1,231 @@
+//! In-app access to the log file
+//!
+//! The log plugin writes to `handy.log` in the app log directory. Once the file
+//! grows past its size limit it is rotated away (with `RotationStrategy::KeepOne`
+//! the old file is removed) and a fresh `handy.log` is started, so readers
+//! track which file an offset belongs to and start over when it changes.
+
+use serde::Serialize;
+use specta::Type;
+use std::fs::File;
+use std::io::{ErrorKind, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use tauri::{AppHandle, Manager};
+
+/// Base name passed to the log plugin's `LogDir` target
+const LOG_FILE_NAME: &str = "handy";
+
+/// Default amount of log text returned by `read_log_file`
+const DEFAULT_READ_BYTES: u64 = 256 * 1024;
+
+/// Upper bound for a single read so a runaway log can't stall the UI
+const MAX_READ_BYTES: u64 = 2 * 1024 * 1024;
+
+#[derive(Serialize, Debug, Clone, Type)]
+pub struct LogChunk {
+    /// Log text, always starting and ending on a line boundary
+    pub content: String,
+    /// Offset in the current log file to pass to the next `tail_log_file` call
+    pub next_offset: u64,
+    /// Identity of the file `next_offset` belongs to, passed back to
+    /// `tail_log_file` along with it
+    pub file_id: String,
+    /// The log was rotated since the given offset and reading restarted from
+    /// the beginning of the new file
+    pub rotated: bool,
+    /// Path of the current log file
+    pub path: String,
+}
+
+fn log_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    app.path()
+        .app_log_dir()
+        .map_err(|e| format!("Failed to get log directory: {}", e))
+}
+
+/// The open log file. Length and identity are taken from the handle so they
+/// describe the file being read even if it is rotated in the meantime.
+struct LogFile {
+    file: File,
+    len: u64,
+    id: String,
+}
+
+/// Open the log file, or `None` if it doesn't exist yet.
+fn open_log(path: &Path) -> Result<Option<LogFile>, String> {
+    let file = match File::open(path) {
+        Ok(file) => file,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(format!("Failed to open {}: {}", path.display(), e)),
+    };
+    let len = file
+        .metadata()
+        .map_err(|e| format!("Failed to read metadata of {}: {}", path.display(), e))?
+        .len();
+    let id = file_identity(&file).unwrap_or_default();
+    Ok(Some(LogFile { file, len, id }))
+}
+
+/// Identifies a file independently of its path. A rotated log can grow past
+/// the previous offset before the next poll, so the length alone can't tell
+/// that the file was replaced.
+#[cfg(unix)]
+fn file_identity(file: &File) -> Option<String> {
+    use std::os::unix::fs::MetadataExt;
+
+    let metadata = file.metadata().ok()?;
+    Some(format!("{}:{}", metadata.dev(), metadata.ino()))
+}
+
+#[cfg(windows)]
+fn file_identity(file: &File) -> Option<String> {
+    use std::os::windows::io::AsRawHandle;
+    use windows::Win32::Foundation::HANDLE;
+    use windows::Win32::Storage::FileSystem::{
+        GetFileInformationByHandle, BY_HANDLE_FILE_INFORMATION,
+    };
+
+    let mut info = BY_HANDLE_FILE_INFORMATION::default();
+    unsafe { GetFileInformationByHandle(HANDLE(file.as_raw_handle()), &mut info) }.ok()?;
+    let index = (u64::from(info.nFileIndexHigh) << 32) | u64::from(info.nFileIndexLow);
+    Some(format!("{}:{}", info.dwVolumeSerialNumber, index))
+}
+
+#[cfg(not(any(unix, windows)))]
+fn file_identity(_file: &File) -> Option<String> {
+    None
+}
+
+fn read_range(file: &mut File, path: &Path, start: u64, len: u64) -> Result<Vec<u8>, String> {
+    file.seek(SeekFrom::Start(start))
+        .map_err(|e| format!("Failed to seek in {}: {}", path.display(), e))?;
+
+    let mut buf = Vec::with_capacity(len as usize);
+    file.take(len)
+        .read_to_end(&mut buf)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    Ok(buf)
+}
+
+/// Drop everything up to and including the first newline, used when a read
+/// starts in the middle of the file.
+fn skip_partial_first_line(bytes: &[u8]) -> &[u8] {
+    match bytes.iter().position(|&b| b == b'\n') {
+        Some(pos) => &bytes[pos + 1..],
+        None => &[],
+    }
+}
+
+/// Length of `bytes` up to and including the last newline, so a line that is
+/// still being written is left for the next read.
+fn complete_lines_len(bytes: &[u8]) -> usize {
+    bytes
+        .iter()
+        .rposition(|&b| b == b'\n')
+        .map(|pos| pos + 1)
+        .unwrap_or(0)
+}
+
+/// Read the last `max_bytes` of the log.
+#[tauri::command]
+#[specta::specta]
+pub fn read_log_file(app: AppHandle, max_bytes: Option<u32>) -> Result<LogChunk, String> {
+    let current = log_dir(&app)?.join(format!("{}.log", LOG_FILE_NAME));
+    let budget = max_bytes
+        .map(u64::from)
+        .unwrap_or(DEFAULT_READ_BYTES)
+        .min(MAX_READ_BYTES);
+
+    let mut log = open_log(&current)?;
+    let len = log.as_ref().map(|log| log.len).unwrap_or(0);
+    let start = len.saturating_sub(budget);
+    let bytes = match log.as_mut() {
+        Some(log) if len > 0 => read_range(&mut log.file, &current, start, len - start)?,
+        _ => Vec::new(),
+    };
+    let lines = if start > 0 {
+        skip_partial_first_line(&bytes)
+    } else {
+        &bytes[..]
+    };
+    let complete = complete_lines_len(lines);
+
+    Ok(LogChunk {
+        content: String::from_utf8_lossy(&lines[..complete]).into_owned(),
+        next_offset: len - (lines.len() - complete) as u64,
+        file_id: log.map(|log| log.id).unwrap_or_default(),
+        rotated: false,
+        path: current.to_string_lossy().to_string(),
+    })
+}
+
+/// Read log lines written since `offset` in the file identified by `file_id`,
+/// both as returned by a previous `read_log_file` or `tail_log_file` call.
+/// Poll this to follow the log.
+#[tauri::command]
+#[specta::specta]
+pub fn tail_log_file(app: AppHandle, offset: u64, file_id: String) -> Result<LogChunk, String> {
+    let current = log_dir(&app)?.join(format!("{}.log", LOG_FILE_NAME));
+    let mut log = open_log(&current)?;
+    let len = log.as_ref().map(|log| log.len).unwrap_or(0);
+    let current_id = log.as_ref().map(|log| log.id.as_str()).unwrap_or_default();
+
+    // A different file (or one that shrank) means the log was rotated, so we
+    // start over on the new one
+    let rotated = current_id != file_id || len < offset;
+    let start = if rotated { 0 } else { offset };
+    let available = (len - start).min(MAX_READ_BYTES);
+
+    let bytes = match log.as_mut() {
+        Some(log) if available > 0 => read_range(&mut log.file, &current, start, available)?,
+        _ => Vec::new(),
+    };
+    let complete = complete_lines_len(&bytes);
+
+    Ok(LogChunk {
+        content: String::from_utf8_lossy(&bytes[..complete]).into_owned(),
+        next_offset: start + complete as u64,
+        file_id: log.map(|log| log.id).unwrap_or_default(),
+        rotated,
+        path: current.to_string_lossy().to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn partial_lines_are_trimmed() {
+        let bytes = b"tail of line\nfull line\nin progress";
+        let trimmed = skip_partial_first_line(bytes);
+        assert_eq!(trimmed, b"full line\nin progress");
+        assert_eq!(&trimmed[..complete_lines_len(trimmed)], b"full line\n");
+
+        assert_eq!(skip_partial_first_line(b"no newline"), b"");
+        assert_eq!(complete_lines_len(b"no newline"), 0);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn rotated_file_has_new_identity() {
+        let dir = std::env::temp_dir();
+        let path = dir.join(format!("handy-log-identity-{}.log", std::process::id()));
+        let rotated_path = path.with_extension("log.1");
+
+        std::fs::write(&path, "first\n").expect("write log");
+        let first = open_log(&path).expect("open log").expect("log exists").id;
+        let again = open_log(&path).expect("open log").expect("log exists").id;
+
+        // Keep the old file around so the new one can't reuse its inode
+        std::fs::rename(&path, &rotated_path).expect("rotate log");
+        std::fs::write(&path, "second\n").expect("write log");
+        let second = open_log(&path).expect("open log").expect("log exists").id;
+
+        let _ = std::fs::remove_file(&path);
+        let _ = std::fs::remove_file(&rotated_path);
+        assert_eq!(first, again);
+        assert_ne!(first, second);
+        assert!(open_log(&path).expect("open log").is_none());
+    }
+}
@@ -1,6 +1,9 @@
 pub mod audio;
 pub mod history;
+pub mod logs;
 pub mod models;
+pub mod permissions;
+pub mod send_to;
 pub mod stt_api;
 pub mod transcription;
 
@@ -49,6 +52,31 @@ pub fn get_log_dir_path(app: AppHandle) -> Result<String, String> {
     Ok(log_dir.to_string_lossy().to_string())
 }
 
+/// Export a diagnostic bundle for bug reports into the Downloads folder and
+/// reveal it in the file manager. Returns the path of the zip.
+#[tauri::command]
+#[specta::specta]
+pub async fn export_diagnostics(app: AppHandle) -> Result<String, String> {
+    let dir = app
+        .path()
+        .download_dir()
+        .or_else(|_| app.path().app_log_dir())
+        .map_err(|e| format!("Failed to get export directory: {}", e))?;
+
+    // Enumerating devices and compressing logs can take a while
+    let bundle_app = app.clone();
+    let path = tauri::async_runtime::spawn_blocking(move || {
+        crate::diagnostics::export_bundle(&bundle_app, &dir)
+    })
+    .await
+    .map_err(|e| format!("Diagnostic export task failed: {}", e))??;
+    if let Err(e) = app.opener().reveal_item_in_dir(&path) {
+        log::warn!("Failed to reveal diagnostic bundle: {}", e);
+    }
+
+    Ok(path.to_string_lossy().to_string())
+}
+
 #[specta::specta]
 #[tauri::command]
 pub fn set_log_level(app: AppHandle, level: LogLevel) -> Result<(), String> {
@@ -67,6 +95,37 @@ pub fn set_log_level(app: AppHandle, level: LogLevel) -> Result<(), String> {
     Ok(())
 }
 
+/// Override the console log level for the current session, or pass `None` to
+/// go back to the level from `RUST_LOG`. Not persisted.
+#[specta::specta]
+#[tauri::command]
+pub fn set_console_log_level(level: Option<LogLevel>) {
+    let value = match level {
+        Some(level) => {
+            let tauri_log_level: tauri_plugin_log::LogLevel = level.into();
+            let log_level: log::Level = tauri_log_level.into();
+            log_level.to_level_filter() as u8
+        }
+        None => crate::CONSOLE_LOG_LEVEL_FROM_ENV,
+    };
+    crate::CONSOLE_LOG_LEVEL.store(value, std::sync::atomic::Ordering::Relaxed);
+}
+
+/// Console log level set with `set_console_log_level`, or `None` while the
+/// console follows `RUST_LOG`.
+#[specta::specta]
+#[tauri::command]
+pub fn get_console_log_level() -> Option<LogLevel> {
+    match crate::CONSOLE_LOG_LEVEL.load(std::sync::atomic::Ordering::Relaxed) {
+        1 => Some(LogLevel::Error),
+        2 => Some(LogLevel::Warn),
+        3 => Some(LogLevel::Info),
+        4 => Some(LogLevel::Debug),
+        5 => Some(LogLevel::Trace),
+        _ => None,
+    }
+}
+
 #[specta::specta]
 #[tauri::command]
 pub fn open_recordings_folder(app: AppHandle) -> Result<(), String> {
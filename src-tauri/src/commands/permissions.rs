@@ -0,0 +1,139 @@
+//! Permission preflight commands
+//!
+//! macOS gates microphone capture, synthetic key events (paste) and global key
+//! listening behind TCC permissions. Without them the first dictation records
+//! silence or fails to paste, so these commands let the UI check and request each
+//! permission up front. Other platforms report every permission as not required.
+
+use crate::settings::{get_settings, KeyboardImplementation};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use tauri::AppHandle;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum PermissionKind {
+    Microphone,
+    Accessibility,
+    InputMonitoring,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum PermissionStatus {
+    Granted,
+    Denied,
+    /// The platform doesn't gate this capability behind a permission
+    NotRequired,
+}
+
+impl PermissionStatus {
+    /// Whether the capability can be used. Anything short of an explicit grant
+    /// counts as missing.
+    pub fn is_satisfied(self) -> bool {
+        matches!(
+            self,
+            PermissionStatus::Granted | PermissionStatus::NotRequired
+        )
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Type)]
+pub struct PermissionsReport {
+    pub microphone: PermissionStatus,
+    /// Needed to paste transcriptions via synthetic key events
+    pub accessibility: PermissionStatus,
+    /// Needed by the handy-keys shortcut implementation to listen for key events
+    pub input_monitoring: PermissionStatus,
+    /// True when every permission needed for recording, pasting and the selected
+    /// shortcut implementation is available
+    pub all_granted: bool,
+}
+
+#[cfg(target_os = "macos")]
+fn to_status(granted: bool) -> PermissionStatus {
+    if granted {
+        PermissionStatus::Granted
+    } else {
+        PermissionStatus::Denied
+    }
+}
+
+async fn check(kind: PermissionKind) -> PermissionStatus {
+    #[cfg(target_os = "macos")]
+    {
+        use tauri_plugin_macos_permissions::{
+            check_accessibility_permission, check_input_monitoring_permission,
+            check_microphone_permission,
+        };
+
+        let granted = match kind {
+            PermissionKind::Microphone => check_microphone_permission().await,
+            PermissionKind::Accessibility => check_accessibility_permission().await,
+            PermissionKind::InputMonitoring => check_input_monitoring_permission().await,
+        };
+        to_status(granted)
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    {
+        let _ = kind;
+        PermissionStatus::NotRequired
+    }
+}
+
+/// Report the status of every permission Handy relies on.
+#[tauri::command]
+#[specta::specta]
+pub async fn get_permissions_status(app: AppHandle) -> Result<PermissionsReport, String> {
+    let microphone = check(PermissionKind::Microphone).await;
+    let accessibility = check(PermissionKind::Accessibility).await;
+    let input_monitoring = check(PermissionKind::InputMonitoring).await;
+
+    // Only handy-keys listens to raw key events; the other shortcut
+    // implementations work without Input Monitoring
+    let needs_input_monitoring =
+        get_settings(&app).keyboard_implementation == KeyboardImplementation::HandyKeys;
+    let mut required = vec![microphone, accessibility];
+    if needs_input_monitoring {
+        required.push(input_monitoring);
+    }
+    let all_granted = required.iter().all(|status| status.is_satisfied());
+
+    Ok(PermissionsReport {
+        microphone,
+        accessibility,
+        input_monitoring,
+        all_granted,
+    })
+}
+
+/// Ask the OS for a permission. On macOS this shows the system prompt the first
+/// time, or opens the matching System Settings pane once the user has already
+/// answered. Returns the status after the request.
+#[tauri::command]
+#[specta::specta]
+pub async fn request_permission(kind: PermissionKind) -> Result<PermissionStatus, String> {
+    #[cfg(target_os = "macos")]
+    {
+        use tauri_plugin_macos_permissions::{
+            request_accessibility_permission, request_input_monitoring_permission,
+            request_microphone_permission,
+        };
+
+        if check(kind).await == PermissionStatus::Granted {
+            return Ok(PermissionStatus::Granted);
+        }
+
+        log::info!("Requesting {:?} permission", kind);
+        match kind {
+            PermissionKind::Microphone => request_microphone_permission()
+                .await
+                .map_err(|e| format!("Failed to request microphone permission: {}", e))?,
+            PermissionKind::Accessibility => request_accessibility_permission().await,
+            PermissionKind::InputMonitoring => request_input_monitoring_permission().await,
+        }
+    }
+
+    Ok(check(kind).await)
+}
@@ -0,0 +1,94 @@
+use crate::managers::history::HistoryManager;
+use crate::send_to::{send_to_connector, validate_connector};
+use crate::settings::{get_settings, write_settings, SendToConnector};
+use std::sync::Arc;
+use tauri::{AppHandle, State};
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_send_to_connectors(app: AppHandle) -> Result<Vec<SendToConnector>, String> {
+    Ok(get_settings(&app).send_to_connectors)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn add_send_to_connector(
+    app: AppHandle,
+    connector: SendToConnector,
+) -> Result<SendToConnector, String> {
+    validate_connector(&connector)?;
+
+    let mut settings = get_settings(&app);
+
+    let new_connector = SendToConnector {
+        id: format!("connector_{}", uuid::Uuid::new_v4()),
+        ..connector
+    };
+
+    settings.send_to_connectors.push(new_connector.clone());
+    write_settings(&app, settings);
+
+    Ok(new_connector)
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn update_send_to_connector(app: AppHandle, connector: SendToConnector) -> Result<(), String> {
+    validate_connector(&connector)?;
+
+    let mut settings = get_settings(&app);
+
+    let existing = settings
+        .send_to_connectors
+        .iter_mut()
+        .find(|c| c.id == connector.id)
+        .ok_or_else(|| format!("Connector with id '{}' not found", connector.id))?;
+
+    *existing = connector;
+    write_settings(&app, settings);
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn delete_send_to_connector(app: AppHandle, id: String) -> Result<(), String> {
+    let mut settings = get_settings(&app);
+
+    let original_len = settings.send_to_connectors.len();
+    settings.send_to_connectors.retain(|c| c.id != id);
+
+    if settings.send_to_connectors.len() == original_len {
+        return Err(format!("Connector with id '{}' not found", id));
+    }
+
+    write_settings(&app, settings);
+    Ok(())
+}
+
+/// Send a history entry to a connector. Uses the post-processed text when available.
+#[tauri::command]
+#[specta::specta]
+pub async fn send_history_entry_to_connector(
+    app: AppHandle,
+    history_manager: State<'_, Arc<HistoryManager>>,
+    id: i64,
+    connector_id: String,
+) -> Result<(), String> {
+    let settings = get_settings(&app);
+    let connector = settings
+        .send_to_connector(&connector_id)
+        .ok_or_else(|| format!("Connector with id '{}' not found", connector_id))?;
+
+    let entry = history_manager
+        .get_entry_by_id(id)
+        .await
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("History entry {} not found", id))?;
+
+    let text = entry
+        .post_processed_text
+        .as_deref()
+        .unwrap_or(&entry.transcription_text);
+
+    send_to_connector(connector, text).await
+}
@@ -1,4 +1,5 @@
 use crate::managers::transcription::TranscriptionManager;
+use crate::metrics::{LatencyRecord, LatencyTracker};
 use crate::settings::{get_settings, write_settings, ModelUnloadTimeout};
 use serde::Serialize;
 use specta::Type;
@@ -38,3 +39,10 @@ pub fn unload_model_manually(
         .unload_model()
         .map_err(|e| format!("Failed to unload model: {}", e))
 }
+
+/// Per-stage latency of recent transcriptions, newest first.
+#[tauri::command]
+#[specta::specta]
+pub fn get_latency_metrics(latency_tracker: State<LatencyTracker>) -> Vec<LatencyRecord> {
+    latency_tracker.recent()
+}
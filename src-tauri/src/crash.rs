@@ -0,0 +1,628 @@
+//! Opt-in crash reporting
+//!
+//! Panics are written to a JSON report in `crash-reports/` in the app data
+//! directory, together with the pipeline stage that was active. Native crashes
+//! in audio or inference code don't go through the panic hook, so a signal
+//! (Unix) or unhandled exception (Windows) handler leaves a marker file
+//! instead, which is turned into a report on the next launch. The marker holds
+//! the signal or exception code, the pipeline stage, the crashing thread's name
+//! and a backtrace, where the platform lets the handler collect them.
+//!
+//! Nothing is installed until the user enables crash reporting. The hooks chain
+//! to whatever was installed before them (including Rust's own stack overflow
+//! handler), and can't be removed again, so turning the setting off only stops
+//! them from recording anything.
+//!
+//! Reports are only ever uploaded when the user has enabled crash reporting and
+//! the build was configured with an upload endpoint (`HANDY_CRASH_REPORT_URL`).
+
+use crate::pipeline::{self, PipelineStage};
+use crate::settings::get_settings;
+use chrono::{DateTime, Utc};
+use log::{error, info, warn};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Once, OnceLock};
+use tauri::{AppHandle, Manager};
+
+/// Upload endpoint baked in at build time; uploads are disabled without it
+const UPLOAD_ENDPOINT: Option<&str> = option_env!("HANDY_CRASH_REPORT_URL");
+
+/// Marker left behind by the native crash handler
+const NATIVE_MARKER_FILE: &str = "native-crash.marker";
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum CrashKind {
+    Panic,
+    Native,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Type)]
+pub struct CrashReport {
+    pub id: String,
+    pub timestamp: i64,
+    pub kind: CrashKind,
+    pub message: String,
+    /// Source location of the panic
+    pub location: Option<String>,
+    pub thread: Option<String>,
+    pub backtrace: Option<String>,
+    /// Pipeline stage that was active when the crash happened
+    pub stage: PipelineStage,
+    pub app_version: String,
+    pub os: String,
+    pub os_version: String,
+    pub arch: String,
+    #[serde(default)]
+    pub uploaded: bool,
+}
+
+/// Mirrors the crash reporting setting; the installed hooks check it
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static PANICKED: AtomicBool = AtomicBool::new(false);
+static HOOKS_INSTALLED: Once = Once::new();
+static CRASH_DIR: OnceLock<PathBuf> = OnceLock::new();
+static APP_VERSION: OnceLock<String> = OnceLock::new();
+
+fn new_report(
+    timestamp: DateTime<Utc>,
+    kind: CrashKind,
+    message: String,
+    stage: PipelineStage,
+) -> CrashReport {
+    CrashReport {
+        id: format!(
+            "crash-{}-{}",
+            timestamp.format("%Y%m%d-%H%M%S"),
+            timestamp.timestamp_subsec_millis()
+        ),
+        timestamp: timestamp.timestamp(),
+        kind,
+        message,
+        location: None,
+        thread: None,
+        backtrace: None,
+        stage,
+        app_version: APP_VERSION.get().cloned().unwrap_or_default(),
+        os: tauri_plugin_os::platform().to_string(),
+        os_version: tauri_plugin_os::version().to_string(),
+        arch: tauri_plugin_os::arch().to_string(),
+        uploaded: false,
+    }
+}
+
+fn write_report(dir: &Path, report: &CrashReport) -> Result<(), String> {
+    let json = serde_json::to_vec_pretty(report)
+        .map_err(|e| format!("Failed to serialize crash report: {}", e))?;
+    fs::write(dir.join(format!("{}.json", report.id)), json)
+        .map_err(|e| format!("Failed to write crash report: {}", e))
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Box<dyn Any>".to_string()
+    }
+}
+
+fn install_panic_hook() {
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        // Release builds abort on panic, which the native handler must ignore
+        PANICKED.store(true, Ordering::Relaxed);
+
+        if let Some(dir) = CRASH_DIR.get().filter(|_| ENABLED.load(Ordering::Relaxed)) {
+            let mut report = new_report(
+                Utc::now(),
+                CrashKind::Panic,
+                panic_message(info.payload()),
+                pipeline::current_stage(),
+            );
+            report.location = info
+                .location()
+                .map(|l| format!("{}:{}:{}", l.file(), l.line(), l.column()));
+            report.thread = std::thread::current().name().map(str::to_string);
+            report.backtrace = Some(std::backtrace::Backtrace::force_capture().to_string());
+
+            if let Err(e) = write_report(dir, &report) {
+                eprintln!("{}", e);
+            }
+        }
+
+        previous(info);
+    }));
+}
+
+#[cfg(unix)]
+mod native {
+    use super::{ENABLED, PANICKED};
+    use crate::pipeline;
+    use std::ffi::CString;
+    use std::os::unix::ffi::OsStrExt;
+    use std::path::Path;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::OnceLock;
+
+    static MARKER_PATH: OnceLock<CString> = OnceLock::new();
+    static MARKER_WRITTEN: AtomicBool = AtomicBool::new(false);
+    /// Handlers that were installed before ours, in `SIGNALS` order
+    static PREVIOUS: OnceLock<[libc::sigaction; SIGNALS.len()]> = OnceLock::new();
+
+    const SIGNALS: [libc::c_int; 5] = [
+        libc::SIGSEGV,
+        libc::SIGBUS,
+        libc::SIGILL,
+        libc::SIGFPE,
+        libc::SIGABRT,
+    ];
+
+    #[cfg(any(all(target_os = "linux", target_env = "gnu"), target_os = "macos"))]
+    const MAX_FRAMES: usize = 64;
+
+    // Provided by glibc and macOS (execinfo). `backtrace_symbols_fd` writes
+    // straight to the file descriptor without allocating.
+    #[cfg(any(all(target_os = "linux", target_env = "gnu"), target_os = "macos"))]
+    extern "C" {
+        fn backtrace(buffer: *mut *mut libc::c_void, size: libc::c_int) -> libc::c_int;
+        fn backtrace_symbols_fd(
+            buffer: *const *mut libc::c_void,
+            size: libc::c_int,
+            fd: libc::c_int,
+        );
+    }
+
+    /// Append the decimal digits of `value` to `buf` without allocating.
+    fn push_decimal(buf: &mut [u8], len: &mut usize, value: u32) {
+        let mut digits = [0u8; 10];
+        let mut count = 0;
+        let mut value = value;
+        loop {
+            digits[count] = b'0' + (value % 10) as u8;
+            count += 1;
+            value /= 10;
+            if value == 0 {
+                break;
+            }
+        }
+        while count > 0 && *len < buf.len() {
+            count -= 1;
+            buf[*len] = digits[count];
+            *len += 1;
+        }
+    }
+
+    /// Write the name of the current thread to `fd`, where it can be read
+    /// without allocating.
+    unsafe fn write_thread_name(fd: libc::c_int) {
+        #[cfg(target_os = "linux")]
+        {
+            let mut name = [0u8; 16];
+            if libc::prctl(libc::PR_GET_NAME, name.as_mut_ptr()) == 0 {
+                let len = name.iter().position(|&b| b == 0).unwrap_or(name.len());
+                libc::write(fd, name.as_ptr() as *const libc::c_void, len);
+            }
+        }
+        #[cfg(not(target_os = "linux"))]
+        let _ = fd;
+    }
+
+    /// Write the current thread's stack to `fd`, one frame per line.
+    unsafe fn write_backtrace(fd: libc::c_int) {
+        #[cfg(any(all(target_os = "linux", target_env = "gnu"), target_os = "macos"))]
+        {
+            let mut frames = [std::ptr::null_mut(); MAX_FRAMES];
+            let count = backtrace(frames.as_mut_ptr(), MAX_FRAMES as libc::c_int);
+            backtrace_symbols_fd(frames.as_ptr(), count, fd);
+        }
+        #[cfg(not(any(all(target_os = "linux", target_env = "gnu"), target_os = "macos")))]
+        let _ = fd;
+    }
+
+    // Only async-signal-safe calls are allowed in here
+    extern "C" fn handle_signal(signal: libc::c_int) {
+        // A chained handler may abort after us (Rust's stack overflow handler does);
+        // keep the marker for the original signal
+        if ENABLED.load(Ordering::Relaxed)
+            && !PANICKED.load(Ordering::Relaxed)
+            && !MARKER_WRITTEN.swap(true, Ordering::Relaxed)
+        {
+            if let Some(path) = MARKER_PATH.get() {
+                let mut buf = [0u8; 32];
+                let mut len = 0;
+                push_decimal(&mut buf, &mut len, signal as u32);
+                buf[len] = b' ';
+                len += 1;
+                push_decimal(&mut buf, &mut len, pipeline::current_stage() as u32);
+                buf[len] = b'\n';
+                len += 1;
+
+                unsafe {
+                    let fd = libc::open(
+                        path.as_ptr(),
+                        libc::O_WRONLY | libc::O_CREAT | libc::O_TRUNC,
+                        0o644,
+                    );
+                    if fd >= 0 {
+                        libc::write(fd, buf.as_ptr() as *const libc::c_void, len);
+                        write_thread_name(fd);
+                        libc::write(fd, b"\n".as_ptr() as *const libc::c_void, 1);
+                        write_backtrace(fd);
+                        libc::close(fd);
+                    }
+                }
+            }
+        }
+
+        // Put the previous handler back and raise the signal again so it handles
+        // it. The signal stays blocked until we return, so the previous handler
+        // runs once this one is done. Signals sent with kill or raise would
+        // otherwise be lost, as they don't recur when the handler returns.
+        let index = SIGNALS.iter().position(|&s| s == signal);
+        unsafe {
+            match (PREVIOUS.get(), index) {
+                (Some(previous), Some(index)) => {
+                    libc::sigaction(signal, &previous[index], std::ptr::null_mut());
+                }
+                _ => {
+                    libc::signal(signal, libc::SIG_DFL);
+                }
+            }
+            libc::raise(signal);
+        }
+    }
+
+    pub fn install(marker: &Path) {
+        let Ok(path) = CString::new(marker.as_os_str().as_bytes()) else {
+            return;
+        };
+        let _ = MARKER_PATH.set(path);
+
+        unsafe {
+            let mut action: libc::sigaction = std::mem::zeroed();
+            action.sa_sigaction = handle_signal as libc::sighandler_t;
+            // Run on the alternate stack Rust sets up so stack overflows are caught too
+            action.sa_flags = libc::SA_ONSTACK;
+            libc::sigemptyset(&mut action.sa_mask);
+
+            let mut previous: [libc::sigaction; SIGNALS.len()] = std::mem::zeroed();
+            for (signal, previous) in SIGNALS.iter().zip(previous.iter_mut()) {
+                libc::sigaction(*signal, std::ptr::null(), previous);
+            }
+            // Saved before ours go in so the handler always has something to restore
+            let _ = PREVIOUS.set(previous);
+
+            // The first call loads the unwinder, which allocates, so do it now
+            // rather than in the handler
+            #[cfg(any(all(target_os = "linux", target_env = "gnu"), target_os = "macos"))]
+            {
+                let mut frames = [std::ptr::null_mut(); 1];
+                backtrace(frames.as_mut_ptr(), 1);
+            }
+
+            for signal in SIGNALS {
+                libc::sigaction(signal, &action, std::ptr::null_mut());
+            }
+        }
+    }
+
+    pub fn describe(code: u32) -> String {
+        let name = match code as libc::c_int {
+            libc::SIGSEGV => "SIGSEGV",
+            libc::SIGBUS => "SIGBUS",
+            libc::SIGILL => "SIGILL",
+            libc::SIGFPE => "SIGFPE",
+            libc::SIGABRT => "SIGABRT",
+            _ => return format!("Fatal signal {}", code),
+        };
+        format!("Fatal signal {} ({})", code, name)
+    }
+}
+
+#[cfg(windows)]
+mod native {
+    use super::{ENABLED, PANICKED};
+    use crate::pipeline;
+    use std::path::{Path, PathBuf};
+    use std::sync::atomic::Ordering;
+    use std::sync::OnceLock;
+    use windows::Win32::System::Diagnostics::Debug::{
+        SetUnhandledExceptionFilter, EXCEPTION_POINTERS, LPTOP_LEVEL_EXCEPTION_FILTER,
+    };
+
+    static MARKER_PATH: OnceLock<PathBuf> = OnceLock::new();
+    static PREVIOUS: OnceLock<LPTOP_LEVEL_EXCEPTION_FILTER> = OnceLock::new();
+
+    /// Too little stack is left to capture a backtrace after this exception
+    const EXCEPTION_STACK_OVERFLOW: u32 = 0xC00000FD;
+
+    unsafe extern "system" fn handle_exception(info: *const EXCEPTION_POINTERS) -> i32 {
+        if ENABLED.load(Ordering::Relaxed) && !PANICKED.load(Ordering::Relaxed) {
+            if let Some(path) = MARKER_PATH.get() {
+                let code = info
+                    .as_ref()
+                    .and_then(|info| info.ExceptionRecord.as_ref())
+                    .map(|record| record.ExceptionCode.0 as u32)
+                    .unwrap_or(0);
+                let thread = std::thread::current()
+                    .name()
+                    .unwrap_or_default()
+                    .to_string();
+                let backtrace = if code == EXCEPTION_STACK_OVERFLOW {
+                    String::new()
+                } else {
+                    std::backtrace::Backtrace::force_capture().to_string()
+                };
+                let _ = std::fs::write(
+                    path,
+                    format!(
+                        "{} {}\n{}\n{}",
+                        code,
+                        pipeline::current_stage() as u8,
+                        thread,
+                        backtrace
+                    ),
+                );
+            }
+        }
+
+        match PREVIOUS.get().copied().flatten() {
+            Some(previous) => previous(info),
+            // EXCEPTION_CONTINUE_SEARCH: let Windows terminate the process as usual
+            None => 0,
+        }
+    }
+
+    pub fn install(marker: &Path) {
+        let _ = MARKER_PATH.set(marker.to_path_buf());
+        let previous = unsafe { SetUnhandledExceptionFilter(Some(handle_exception)) };
+        let _ = PREVIOUS.set(previous);
+    }
+
+    pub fn describe(code: u32) -> String {
+        format!("Unhandled exception 0x{:08X}", code)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct NativeMarker {
+    code: u32,
+    stage: PipelineStage,
+    thread: Option<String>,
+    backtrace: Option<String>,
+}
+
+/// Parse a native crash marker: `"<code> <stage>"` on the first line, then the
+/// thread name and the backtrace, both of which may be empty.
+fn parse_marker(contents: &str) -> Option<NativeMarker> {
+    let mut lines = contents.splitn(3, '\n');
+    let mut parts = lines.next()?.split_whitespace();
+    let code = parts.next()?.parse().ok()?;
+    let stage = parts
+        .next()
+        .and_then(|s| s.parse().ok())
+        .map(PipelineStage::from_u8)
+        .unwrap_or(PipelineStage::Idle);
+    let thread = lines
+        .next()
+        .map(str::trim)
+        .filter(|thread| !thread.is_empty())
+        .map(str::to_string);
+    let backtrace = lines
+        .next()
+        .map(str::trim_end)
+        .filter(|backtrace| !backtrace.is_empty())
+        .map(str::to_string);
+    Some(NativeMarker {
+        code,
+        stage,
+        thread,
+        backtrace,
+    })
+}
+
+/// Turn a marker left by the native handler in a previous run into a report.
+fn collect_native_crash(dir: &Path) {
+    let marker = dir.join(NATIVE_MARKER_FILE);
+    let Ok(contents) = fs::read_to_string(&marker) else {
+        return;
+    };
+
+    if let Some(marker_info) = parse_marker(&contents) {
+        let timestamp = fs::metadata(&marker)
+            .and_then(|m| m.modified())
+            .map(DateTime::<Utc>::from)
+            .unwrap_or_else(|_| Utc::now());
+
+        #[cfg(any(unix, windows))]
+        let message = native::describe(marker_info.code);
+        #[cfg(not(any(unix, windows)))]
+        let message = format!("Native crash {}", marker_info.code);
+
+        let mut report = new_report(timestamp, CrashKind::Native, message, marker_info.stage);
+        report.thread = marker_info.thread;
+        report.backtrace = marker_info.backtrace;
+        match write_report(dir, &report) {
+            Ok(()) => warn!(
+                "Handy crashed during the previous run ({:?}): {}",
+                report.stage, report.message
+            ),
+            Err(e) => error!("{}", e),
+        }
+    }
+
+    let _ = fs::remove_file(&marker);
+}
+
+pub fn crash_dir(app: &AppHandle) -> Result<PathBuf, String> {
+    app.path()
+        .app_data_dir()
+        .map(|dir| dir.join("crash-reports"))
+        .map_err(|e| format!("Failed to get app data directory: {}", e))
+}
+
+/// Pick up any native crash from the previous run and, if the user has opted
+/// in, install the panic hook and native crash handler. Call as early in setup
+/// as possible.
+pub fn install(app: &AppHandle) {
+    let dir = match crash_dir(app) {
+        Ok(dir) => dir,
+        Err(e) => {
+            error!("Crash reporting disabled: {}", e);
+            return;
+        }
+    };
+    if let Err(e) = fs::create_dir_all(&dir) {
+        error!(
+            "Crash reporting disabled: failed to create {}: {}",
+            dir.display(),
+            e
+        );
+        return;
+    }
+
+    let _ = APP_VERSION.set(app.package_info().version.to_string());
+    collect_native_crash(&dir);
+    let _ = CRASH_DIR.set(dir);
+
+    set_enabled(get_settings(app).crash_reporting_enabled);
+}
+
+/// Follow the crash reporting setting. The hooks are installed the first time
+/// it is enabled.
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+    if !enabled {
+        return;
+    }
+    let Some(dir) = CRASH_DIR.get() else {
+        return;
+    };
+
+    HOOKS_INSTALLED.call_once(|| {
+        #[cfg(any(unix, windows))]
+        native::install(&dir.join(NATIVE_MARKER_FILE));
+        install_panic_hook();
+    });
+}
+
+fn load_reports(dir: &Path) -> Vec<(PathBuf, CrashReport)> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let mut reports: Vec<(PathBuf, CrashReport)> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().and_then(|ext| ext.to_str()) == Some("json"))
+        .filter_map(|path| {
+            let contents = fs::read(&path).ok()?;
+            let report = serde_json::from_slice(&contents).ok()?;
+            Some((path, report))
+        })
+        .collect();
+    reports.sort_by(|a, b| b.1.timestamp.cmp(&a.1.timestamp));
+    reports
+}
+
+pub fn upload_available() -> bool {
+    UPLOAD_ENDPOINT.is_some_and(|url| !url.is_empty())
+}
+
+/// Upload reports that haven't been sent yet. Does nothing unless the user
+/// has opted in and the build has an upload endpoint.
+pub async fn upload_pending_reports(app: &AppHandle) {
+    if !get_settings(app).crash_reporting_enabled || !upload_available() {
+        return;
+    }
+    let (Some(endpoint), Ok(dir)) = (UPLOAD_ENDPOINT, crash_dir(app)) else {
+        return;
+    };
+
+    let client = reqwest::Client::new();
+    for (path, mut report) in load_reports(&dir) {
+        if report.uploaded {
+            continue;
+        }
+
+        match client.post(endpoint).json(&report).send().await {
+            Ok(response) if response.status().is_success() => {
+                info!("Uploaded crash report {}", report.id);
+                report.uploaded = true;
+                if let Ok(json) = serde_json::to_vec_pretty(&report) {
+                    let _ = fs::write(&path, json);
+                }
+            }
+            Ok(response) => {
+                warn!(
+                    "Crash report upload rejected with status {}",
+                    response.status()
+                );
+                return;
+            }
+            Err(e) => {
+                warn!("Failed to upload crash report: {}", e);
+                return;
+            }
+        }
+    }
+}
+
+/// Crash reports stored on this machine, newest first.
+#[tauri::command]
+#[specta::specta]
+pub fn get_crash_reports(app: AppHandle) -> Result<Vec<CrashReport>, String> {
+    let dir = crash_dir(&app)?;
+    Ok(load_reports(&dir)
+        .into_iter()
+        .map(|(_, report)| report)
+        .collect())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn delete_crash_reports(app: AppHandle) -> Result<(), String> {
+    let dir = crash_dir(&app)?;
+    for (path, _) in load_reports(&dir) {
+        fs::remove_file(&path)
+            .map_err(|e| format!("Failed to delete {}: {}", path.display(), e))?;
+    }
+    Ok(())
+}
+
+/// Whether this build can upload crash reports at all.
+#[tauri::command]
+#[specta::specta]
+pub fn is_crash_upload_available() -> bool {
+    upload_available()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_native_marker() {
+        let marker = parse_marker("11 2\nhandy-worker-0\nframe 0\nframe 1\n").expect("marker");
+        assert_eq!(marker.code, 11);
+        assert_eq!(marker.stage, PipelineStage::Transcribing);
+        assert_eq!(marker.thread.as_deref(), Some("handy-worker-0"));
+        assert_eq!(marker.backtrace.as_deref(), Some("frame 0\nframe 1"));
+
+        let marker = parse_marker("6 0\n\n").expect("marker");
+        assert_eq!(marker.thread, None);
+        assert_eq!(marker.backtrace, None);
+
+        assert_eq!(
+            parse_marker("6").map(|m| m.stage),
+            Some(PipelineStage::Idle)
+        );
+        assert_eq!(parse_marker(""), None);
+    }
+}
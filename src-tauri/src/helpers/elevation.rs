@@ -0,0 +1,261 @@
+//! Detection of pastes blocked by User Interface Privilege Isolation (UIPI)
+//!
+//! On Windows a process can't send input to a window owned by a process with a
+//! higher integrity level, so when the focused app runs as Administrator (an
+//! elevated terminal, an installer) our simulated paste is silently dropped.
+//! Two setups are allowed through: Handy itself running elevated, or a build
+//! signed, installed under Program Files and embedding a `uiAccess="true"`
+//! manifest. Everywhere else we detect the case and leave the transcript on the
+//! clipboard instead.
+
+use serde::Serialize;
+use specta::Type;
+use tauri::AppHandle;
+
+#[derive(Serialize, Debug, Clone, Copy, Type)]
+pub struct ElevationStatus {
+    /// Handy is running as Administrator
+    pub process_elevated: bool,
+    /// Handy was started with UIAccess, which bypasses UIPI
+    pub ui_access: bool,
+}
+
+#[cfg(target_os = "windows")]
+mod win {
+    use windows::Win32::Foundation::{CloseHandle, HANDLE};
+    use windows::Win32::Security::{
+        GetTokenInformation, TokenElevation, TokenUIAccess, TOKEN_INFORMATION_CLASS, TOKEN_QUERY,
+    };
+    use windows::Win32::System::Threading::{
+        GetCurrentProcess, OpenProcess, OpenProcessToken, PROCESS_QUERY_LIMITED_INFORMATION,
+    };
+    use windows::Win32::UI::WindowsAndMessaging::{GetForegroundWindow, GetWindowThreadProcessId};
+
+    /// Read a `u32`-sized token information class from a process token.
+    unsafe fn query_token_u32(process: HANDLE, class: TOKEN_INFORMATION_CLASS) -> Option<u32> {
+        let mut token = HANDLE::default();
+        OpenProcessToken(process, TOKEN_QUERY, &mut token).ok()?;
+
+        let mut value: u32 = 0;
+        let mut returned: u32 = 0;
+        let result = GetTokenInformation(
+            token,
+            class,
+            Some(&mut value as *mut u32 as *mut _),
+            std::mem::size_of::<u32>() as u32,
+            &mut returned,
+        );
+        let _ = CloseHandle(token);
+        result.ok().map(|_| value)
+    }
+
+    pub fn is_current_process_elevated() -> bool {
+        unsafe { query_token_u32(GetCurrentProcess(), TokenElevation).unwrap_or(0) != 0 }
+    }
+
+    pub fn has_ui_access() -> bool {
+        unsafe { query_token_u32(GetCurrentProcess(), TokenUIAccess).unwrap_or(0) != 0 }
+    }
+
+    /// Whether the process owning the foreground window is elevated.
+    /// Returns `None` when there is no foreground window.
+    pub fn is_foreground_window_elevated() -> Option<bool> {
+        unsafe {
+            let hwnd = GetForegroundWindow();
+            if hwnd.is_invalid() {
+                return None;
+            }
+
+            let mut pid: u32 = 0;
+            GetWindowThreadProcessId(hwnd, Some(&mut pid as *mut u32));
+            if pid == 0 {
+                return None;
+            }
+
+            // Protected processes refuse even limited queries without being
+            // elevated, so a failure here is treated as unknown
+            let process = OpenProcess(PROCESS_QUERY_LIMITED_INFORMATION, false, pid).ok()?;
+            let elevated = query_token_u32(process, TokenElevation);
+            let _ = CloseHandle(process);
+
+            elevated.map(|v| v != 0)
+        }
+    }
+
+    /// Block until the given process exits, giving up after `timeout_ms`.
+    pub fn wait_for_process_exit(pid: u32, timeout_ms: u32) {
+        use windows::Win32::System::Threading::{WaitForSingleObject, PROCESS_SYNCHRONIZE};
+
+        unsafe {
+            // The process may already be gone, which is what we're waiting for
+            if let Ok(process) = OpenProcess(PROCESS_SYNCHRONIZE, false, pid) {
+                WaitForSingleObject(process, timeout_ms);
+                let _ = CloseHandle(process);
+            }
+        }
+    }
+}
+
+/// How long a relaunched instance waits for the instance it replaces to exit
+const REPLACED_PROCESS_TIMEOUT_MS: u32 = 10_000;
+
+/// Quote an argument for a Windows command line so `CommandLineToArgvW` splits
+/// it back into the same string.
+#[cfg(any(target_os = "windows", test))]
+fn quote_windows_arg(arg: &str) -> String {
+    if !arg.is_empty() && !arg.contains([' ', '\t', '\n', '\u{b}', '"']) {
+        return arg.to_string();
+    }
+
+    let mut quoted = String::from('"');
+    let mut backslashes = 0;
+    for c in arg.chars() {
+        match c {
+            '\\' => backslashes += 1,
+            '"' => {
+                // Backslashes before a quote are escaped, then the quote itself
+                quoted.push_str(&"\\".repeat(backslashes * 2 + 1));
+                quoted.push('"');
+                backslashes = 0;
+            }
+            _ => {
+                quoted.push_str(&"\\".repeat(backslashes));
+                quoted.push(c);
+                backslashes = 0;
+            }
+        }
+    }
+    // Trailing backslashes would otherwise escape the closing quote
+    quoted.push_str(&"\\".repeat(backslashes * 2));
+    quoted.push('"');
+    quoted
+}
+
+/// Wait for the instance this one replaces to exit. Called before the
+/// single-instance plugin starts, which would otherwise hand this process
+/// over to the old one.
+pub fn wait_for_replaced_instance(pid: u32) {
+    #[cfg(target_os = "windows")]
+    {
+        log::info!("Waiting for previous instance (pid {}) to exit", pid);
+        win::wait_for_process_exit(pid, REPLACED_PROCESS_TIMEOUT_MS);
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = (pid, REPLACED_PROCESS_TIMEOUT_MS);
+    }
+}
+
+/// Report whether Handy can reach elevated windows.
+pub fn elevation_status() -> ElevationStatus {
+    #[cfg(target_os = "windows")]
+    {
+        ElevationStatus {
+            process_elevated: win::is_current_process_elevated(),
+            ui_access: win::has_ui_access(),
+        }
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        ElevationStatus {
+            process_elevated: false,
+            ui_access: false,
+        }
+    }
+}
+
+/// True when the focused window belongs to an elevated process that UIPI
+/// won't let us send input to. Always false outside Windows.
+pub fn is_paste_blocked_by_uipi() -> bool {
+    #[cfg(target_os = "windows")]
+    {
+        let status = elevation_status();
+        if status.process_elevated || status.ui_access {
+            return false;
+        }
+        win::is_foreground_window_elevated().unwrap_or(false)
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        false
+    }
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_elevation_status() -> ElevationStatus {
+    elevation_status()
+}
+
+/// Relaunch Handy as Administrator so it can paste into elevated windows.
+/// Shows the UAC prompt; the current instance exits once the new one is started.
+#[tauri::command]
+#[specta::specta]
+pub fn restart_as_administrator(app: AppHandle) -> Result<(), String> {
+    #[cfg(target_os = "windows")]
+    {
+        use windows::core::{HSTRING, PCWSTR};
+        use windows::Win32::UI::Shell::ShellExecuteW;
+        use windows::Win32::UI::WindowsAndMessaging::SW_SHOWNORMAL;
+
+        let exe =
+            std::env::current_exe().map_err(|e| format!("Failed to locate executable: {}", e))?;
+
+        // The new instance waits for this one to exit before claiming the
+        // single-instance lock, otherwise it would hand itself over to us
+        let mut args: Vec<String> = Vec::new();
+        let mut original = std::env::args().skip(1);
+        while let Some(arg) = original.next() {
+            if arg == crate::cli::REPLACES_PID_ARG {
+                original.next();
+            } else {
+                args.push(arg);
+            }
+        }
+        args.push(crate::cli::REPLACES_PID_ARG.to_string());
+        args.push(std::process::id().to_string());
+
+        let params: Vec<String> = args.iter().map(|arg| quote_windows_arg(arg)).collect();
+        let verb = HSTRING::from("runas");
+        let file = HSTRING::from(exe.as_os_str());
+        let params = HSTRING::from(params.join(" "));
+
+        let result =
+            unsafe { ShellExecuteW(None, &verb, &file, &params, PCWSTR::null(), SW_SHOWNORMAL) };
+
+        // ShellExecute returns a value greater than 32 on success
+        if result.0 as isize <= 32 {
+            return Err("Elevation was cancelled or failed".to_string());
+        }
+
+        log::info!("Restarting Handy as Administrator");
+        app.exit(0);
+        Ok(())
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = app;
+        Err("Restarting as Administrator is only supported on Windows".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::quote_windows_arg;
+
+    #[test]
+    fn quotes_windows_arguments() {
+        assert_eq!(quote_windows_arg("--headless"), "--headless");
+        assert_eq!(quote_windows_arg(""), r#""""#);
+        assert_eq!(quote_windows_arg("a b"), r#""a b""#);
+        assert_eq!(quote_windows_arg(r#"say "hi""#), r#""say \"hi\"""#);
+        assert_eq!(
+            quote_windows_arg(r"C:\Program Files\Handy\"),
+            r#""C:\Program Files\Handy\\""#
+        );
+    }
+}
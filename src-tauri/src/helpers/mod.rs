@@ -1 +1,3 @@
 pub mod clamshell;
+pub mod elevation;
+pub mod secure_input;
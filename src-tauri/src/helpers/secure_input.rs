@@ -0,0 +1,26 @@
+//! macOS Secure Event Input detection
+//!
+//! Password fields, login prompts and some password managers and terminals
+//! enable Secure Event Input, which stops other processes from observing or
+//! injecting keystrokes. Simulated pastes are dropped without any error while it
+//! is on, so we check for it before pasting.
+
+#[cfg(target_os = "macos")]
+#[link(name = "Carbon", kind = "framework")]
+extern "C" {
+    fn IsSecureEventInputEnabled() -> u8;
+}
+
+/// Whether any process currently has Secure Event Input enabled.
+/// Always false outside macOS.
+pub fn is_secure_input_enabled() -> bool {
+    #[cfg(target_os = "macos")]
+    {
+        unsafe { IsSecureEventInputEnabled() != 0 }
+    }
+
+    #[cfg(not(target_os = "macos"))]
+    {
+        false
+    }
+}
@@ -3,20 +3,28 @@ mod actions;
 mod apple_intelligence;
 mod audio_feedback;
 pub mod audio_toolkit;
+mod cli;
 mod clipboard;
 mod commands;
+mod crash;
+mod diagnostics;
 mod helpers;
 mod input;
 mod llm_client;
 mod managers;
+mod metrics;
 mod overlay;
+mod pipeline;
+mod send_to;
 mod settings;
 mod shortcut;
 mod signal_handle;
 mod stt_client;
 mod tray;
 mod tray_i18n;
+mod updater;
 mod utils;
+mod worker_pool;
 use specta_typescript::{BigIntExportBehavior, Typescript};
 use tauri_specta::{collect_commands, Builder};
 
@@ -40,12 +48,20 @@ use tauri::{AppHandle, Manager};
 use tauri_plugin_autostart::{MacosLauncher, ManagerExt};
 use tauri_plugin_log::{Builder as LogBuilder, RotationStrategy, Target, TargetKind};
 
+use crate::cli::CliArgs;
+use crate::metrics::LatencyTracker;
 use crate::settings::get_settings;
+use crate::updater::UpdateState;
 
 // Global atomic to store the file log level filter
 // We use u8 to store the log::LevelFilter as a number
 pub static FILE_LOG_LEVEL: AtomicU8 = AtomicU8::new(log::LevelFilter::Debug as u8);
 
+// Console log level set from the app for the current session. While it holds
+// CONSOLE_LOG_LEVEL_FROM_ENV the console follows RUST_LOG instead.
+pub const CONSOLE_LOG_LEVEL_FROM_ENV: u8 = u8::MAX;
+pub static CONSOLE_LOG_LEVEL: AtomicU8 = AtomicU8::new(CONSOLE_LOG_LEVEL_FROM_ENV);
+
 fn level_filter_from_u8(value: u8) -> log::LevelFilter {
     match value {
         0 => log::LevelFilter::Off,
@@ -88,8 +104,37 @@ struct ShortcutToggleStates {
 
 type ManagedToggleState = Mutex<ShortcutToggleStates>;
 
-fn show_main_window(app: &AppHandle) {
+/// Returns the main window, creating it from the app config if it doesn't exist yet.
+/// The window isn't created at startup in headless mode, so it's built lazily the
+/// first time the user asks for it (e.g. via the tray's Settings item).
+fn get_or_create_main_window(app: &AppHandle) -> Option<tauri::WebviewWindow> {
     if let Some(main_window) = app.get_webview_window("main") {
+        return Some(main_window);
+    }
+
+    let Some(config) = app
+        .config()
+        .app
+        .windows
+        .iter()
+        .find(|window| window.label == "main")
+        .cloned()
+    else {
+        log::error!("Main window config not found.");
+        return None;
+    };
+
+    match tauri::WebviewWindowBuilder::from_config(app, &config).and_then(|b| b.build()) {
+        Ok(main_window) => Some(main_window),
+        Err(e) => {
+            log::error!("Failed to create main window: {}", e);
+            None
+        }
+    }
+}
+
+fn show_main_window(app: &AppHandle) {
+    if let Some(main_window) = get_or_create_main_window(app) {
         // First, ensure the window is visible
         if let Err(e) = main_window.show() {
             log::error!("Failed to show window: {}", e);
@@ -146,11 +191,13 @@ fn initialize_core_logic(app_handle: &AppHandle) {
     #[cfg(unix)]
     signal_handle::setup_signal_handler(app_handle.clone(), signals);
 
+    let headless = cli::is_headless(app_handle);
+
     // Apply macOS Accessory policy if starting hidden
     #[cfg(target_os = "macos")]
     {
         let settings = settings::get_settings(app_handle);
-        if settings.start_hidden {
+        if cli::should_start_hidden(app_handle, &settings) || headless {
             let _ = app_handle.set_activation_policy(tauri::ActivationPolicy::Accessory);
         }
     }
@@ -183,6 +230,15 @@ fn initialize_core_logic(app_handle: &AppHandle) {
                     let _ = app.emit("check-for-updates", ());
                 }
             }
+            "install_update" => {
+                show_main_window(app);
+                let app = app.clone();
+                tauri::async_runtime::spawn(async move {
+                    if let Err(e) = updater::install_pending_update(&app).await {
+                        log::error!("Failed to install update: {}", e);
+                    }
+                });
+            }
             "copy_last_transcript" => {
                 tray::copy_last_transcript(app);
             }
@@ -204,16 +260,34 @@ fn initialize_core_logic(app_handle: &AppHandle) {
     // Initialize tray menu with idle state
     utils::update_tray_menu(app_handle, &utils::TrayIconState::Idle, None);
 
-    // Get the autostart manager and configure based on user setting
-    let autostart_manager = app_handle.autolaunch();
-    let settings = settings::get_settings(&app_handle);
+    // The launch-at-login entry can be removed through the system settings, so
+    // the stored setting follows the OS rather than recreating the entry
+    match app_handle.autolaunch().is_enabled() {
+        Ok(enabled) => {
+            let mut settings = settings::get_settings(&app_handle);
+            if settings.autostart_enabled != enabled {
+                log::info!("Syncing launch-at-login setting from the OS: {}", enabled);
+                settings.autostart_enabled = enabled;
+                settings::write_settings(&app_handle, settings);
+            }
+        }
+        Err(e) => log::warn!("Failed to read launch-at-login status: {}", e),
+    }
 
-    if settings.autostart_enabled {
-        // Enable autostart if user has opted in
-        let _ = autostart_manager.enable();
-    } else {
-        // Disable autostart if user has opted out
-        let _ = autostart_manager.disable();
+    if headless {
+        // Without a webview there is no frontend to call `initialize_enigo` and
+        // `initialize_shortcuts`, so do it here. On macOS this assumes permissions
+        // were already granted during a previous windowed run.
+        if let Err(e) = commands::initialize_enigo(app_handle.clone()) {
+            log::warn!("Headless mode: {}", e);
+        }
+        if let Err(e) = commands::initialize_shortcuts(app_handle.clone()) {
+            log::warn!("Headless mode: failed to initialize shortcuts: {}", e);
+        }
+        log::info!("Running in headless mode; the main window will not be created");
+        // The recording overlay is a webview too and is intentionally skipped.
+        // Showing and hiding it is a no-op while the window doesn't exist.
+        return;
     }
 
     // Create the recording overlay window (hidden by default)
@@ -237,6 +311,13 @@ pub fn run() {
     // Parse console logging directives from RUST_LOG, falling back to info-level logging
     // when the variable is unset
     let console_filter = build_console_filter();
+    let cli_args = CliArgs::from_env();
+
+    // After a restart as Administrator, let the old instance release the
+    // single-instance lock before the plugin below tries to claim it
+    if let Some(pid) = cli_args.replaces_pid {
+        helpers::elevation::wait_for_replaced_instance(pid);
+    }
 
     let specta_builder = Builder::<tauri::Wry>::new().commands(collect_commands![
         shortcut::change_binding,
@@ -247,6 +328,7 @@ pub fn run() {
         shortcut::change_sound_theme_setting,
         shortcut::change_start_hidden_setting,
         shortcut::change_autostart_setting,
+        shortcut::change_autostart_start_hidden_setting,
         shortcut::change_translate_to_english_setting,
         shortcut::change_selected_language_setting,
         shortcut::change_overlay_position_setting,
@@ -254,6 +336,7 @@ pub fn run() {
         shortcut::change_word_correction_threshold_setting,
         shortcut::change_paste_method_setting,
         shortcut::change_clipboard_handling_setting,
+        shortcut::change_typing_tool_setting,
         shortcut::change_post_process_enabled_setting,
         shortcut::change_experimental_enabled_setting,
         shortcut::change_post_process_base_url_setting,
@@ -272,19 +355,29 @@ pub fn run() {
         shortcut::change_append_trailing_space_setting,
         shortcut::change_app_language_setting,
         shortcut::change_update_checks_setting,
+        shortcut::change_update_channel_setting,
+        shortcut::change_crash_reporting_setting,
         shortcut::change_keyboard_implementation_setting,
         shortcut::get_keyboard_implementation,
         shortcut::handy_keys::start_handy_keys_recording,
         shortcut::handy_keys::stop_handy_keys_recording,
         trigger_update_check,
+        updater::check_for_update,
+        updater::get_pending_update,
+        updater::install_update,
         commands::cancel_operation,
         commands::get_app_dir_path,
         commands::get_app_settings,
         commands::get_default_settings,
         commands::get_log_dir_path,
         commands::set_log_level,
+        commands::set_console_log_level,
+        commands::get_console_log_level,
         commands::open_recordings_folder,
         commands::open_log_dir,
+        commands::logs::read_log_file,
+        commands::logs::tail_log_file,
+        commands::export_diagnostics,
         commands::open_app_data_dir,
         commands::check_apple_intelligence_available,
         commands::initialize_enigo,
@@ -322,6 +415,7 @@ pub fn run() {
         commands::audio::is_recording,
         commands::transcription::set_model_unload_timeout,
         commands::transcription::get_model_load_status,
+        commands::transcription::get_latency_metrics,
         commands::transcription::unload_model_manually,
         commands::history::get_history_entries,
         commands::history::toggle_history_entry_saved,
@@ -329,7 +423,19 @@ pub fn run() {
         commands::history::delete_history_entry,
         commands::history::update_history_limit,
         commands::history::update_recording_retention_period,
+        commands::send_to::get_send_to_connectors,
+        commands::send_to::add_send_to_connector,
+        commands::send_to::update_send_to_connector,
+        commands::send_to::delete_send_to_connector,
+        commands::send_to::send_history_entry_to_connector,
+        commands::permissions::get_permissions_status,
+        commands::permissions::request_permission,
         helpers::clamshell::is_laptop,
+        helpers::elevation::get_elevation_status,
+        helpers::elevation::restart_as_administrator,
+        crash::get_crash_reports,
+        crash::delete_crash_reports,
+        crash::is_crash_upload_available,
     ]);
 
     #[cfg(debug_assertions)] // <- Only export on non-release builds
@@ -347,10 +453,13 @@ pub fn run() {
             .rotation_strategy(RotationStrategy::KeepOne)
             .clear_targets()
             .targets([
-                // Console output respects RUST_LOG environment variable
+                // Console output respects RUST_LOG unless overridden at runtime
                 Target::new(TargetKind::Stdout).filter({
                     let console_filter = console_filter.clone();
-                    move |metadata| console_filter.enabled(metadata)
+                    move |metadata| match CONSOLE_LOG_LEVEL.load(Ordering::Relaxed) {
+                        CONSOLE_LOG_LEVEL_FROM_ENV => console_filter.enabled(metadata),
+                        level => metadata.level() <= level_filter_from_u8(level),
+                    }
                 }),
                 // File logs respect the user's settings (stored in FILE_LOG_LEVEL atomic)
                 Target::new(TargetKind::LogDir {
@@ -379,14 +488,18 @@ pub fn run() {
         .plugin(tauri_plugin_os::init())
         .plugin(tauri_plugin_clipboard_manager::init())
         .plugin(tauri_plugin_macos_permissions::init())
+        .plugin(tauri_plugin_notification::init())
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_store::Builder::default().build())
         .plugin(tauri_plugin_global_shortcut::Builder::new().build())
         .plugin(tauri_plugin_autostart::init(
             MacosLauncher::LaunchAgent,
-            Some(vec![]),
+            Some(vec![cli::AUTOSTART_ARG]),
         ))
         .manage(Mutex::new(ShortcutToggleStates::default()))
+        .manage(cli_args)
+        .manage(LatencyTracker::default())
+        .manage(UpdateState::default())
         .setup(move |app| {
             let settings = get_settings(&app.handle());
             let tauri_log_level: tauri_plugin_log::LogLevel = settings.log_level.into();
@@ -395,11 +508,26 @@ pub fn run() {
             FILE_LOG_LEVEL.store(file_log_level.to_level_filter() as u8, Ordering::Relaxed);
             let app_handle = app.handle().clone();
 
+            crash::install(&app_handle);
+            let upload_handle = app_handle.clone();
+            tauri::async_runtime::spawn(async move {
+                crash::upload_pending_reports(&upload_handle).await;
+            });
+
+            // Create the main window before the tray so the initial tray icon can follow
+            // its theme. In headless mode it is only created on demand.
+            let main_window = if cli_args.headless {
+                None
+            } else {
+                get_or_create_main_window(&app_handle)
+            };
+
             initialize_core_logic(&app_handle);
+            updater::start_background_checks(&app_handle);
 
             // Show main window only if not starting hidden
-            if !settings.start_hidden {
-                if let Some(main_window) = app_handle.get_webview_window("main") {
+            if let Some(main_window) = main_window {
+                if !cli::should_start_hidden(&app_handle, &settings) {
                     main_window.show().unwrap();
                     main_window.set_focus().unwrap();
                 }
@@ -0,0 +1,99 @@
+//! Per-stage latency metrics for the transcription pipeline
+//!
+//! Every transcription records how long each stage took. The most recent runs
+//! are kept in memory for `get_latency_metrics`, and each run is also stored
+//! with its history entry.
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::collections::VecDeque;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Number of runs kept in memory
+const RECENT_LIMIT: usize = 50;
+
+/// Stage durations of a single transcription, in milliseconds. Stages that
+/// didn't run (e.g. encoding with a local model) are `None`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, Type)]
+pub struct LatencyMetrics {
+    /// Length of the captured audio
+    pub capture_ms: u64,
+    /// Converting the samples to WAV for the STT API, summed over the chunks
+    /// encoded during the upload
+    pub encode_ms: Option<u64>,
+    /// Sending the audio to the STT API
+    pub upload_ms: Option<u64>,
+    /// Local model inference, or waiting for the STT API to respond
+    pub inference_ms: u64,
+    /// Chinese variant conversion and LLM post-processing
+    pub post_processing_ms: Option<u64>,
+    /// Pasting or typing the text into the focused app
+    pub insertion_ms: Option<u64>,
+    /// From the end of recording until the text was inserted
+    pub total_ms: u64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Type)]
+pub struct LatencyRecord {
+    pub timestamp: i64,
+    pub metrics: LatencyMetrics,
+}
+
+/// In-memory ring of the most recent latency records
+#[derive(Default)]
+pub struct LatencyTracker {
+    recent: Mutex<VecDeque<LatencyRecord>>,
+}
+
+impl LatencyTracker {
+    pub fn record(&self, metrics: LatencyMetrics) {
+        if let Ok(mut recent) = self.recent.lock() {
+            if recent.len() == RECENT_LIMIT {
+                recent.pop_front();
+            }
+            recent.push_back(LatencyRecord {
+                timestamp: Utc::now().timestamp(),
+                metrics,
+            });
+        }
+    }
+
+    /// Recent records, newest first
+    pub fn recent(&self) -> Vec<LatencyRecord> {
+        self.recent
+            .lock()
+            .map(|recent| recent.iter().rev().cloned().collect())
+            .unwrap_or_default()
+    }
+}
+
+pub fn as_ms(duration: Duration) -> u64 {
+    duration.as_millis() as u64
+}
+
+pub fn elapsed_ms(start: Instant) -> u64 {
+    as_ms(start.elapsed())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tracker_keeps_most_recent_first() {
+        let tracker = LatencyTracker::default();
+        for i in 0..(RECENT_LIMIT as u64 + 5) {
+            tracker.record(LatencyMetrics {
+                total_ms: i,
+                ..Default::default()
+            });
+        }
+
+        let recent = tracker.recent();
+        assert_eq!(recent.len(), RECENT_LIMIT);
+        assert_eq!(recent[0].metrics.total_ms, RECENT_LIMIT as u64 + 4);
+        assert_eq!(recent.last().unwrap().metrics.total_ms, 5);
+    }
+}
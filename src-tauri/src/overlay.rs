@@ -1,6 +1,8 @@
 use crate::input;
 use crate::settings;
 use crate::settings::OverlayPosition;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
 use tauri::{AppHandle, Emitter, Manager, PhysicalPosition, PhysicalSize};
 
 #[cfg(not(target_os = "macos"))]
@@ -18,6 +20,13 @@ use tauri_nspanel::{tauri_panel, CollectionBehavior, PanelBuilder, PanelLevel};
 #[cfg(target_os = "linux")]
 use gtk_layer_shell::{Edge, KeyboardMode, Layer, LayerShell};
 
+/// How long the paste-blocked notice stays on screen
+const PASTE_BLOCKED_NOTICE_DURATION: Duration = Duration::from_secs(4);
+
+/// Bumped every time the overlay is shown, so a delayed hide can tell whether
+/// the overlay has been reused for something else in the meantime
+static OVERLAY_GENERATION: AtomicU64 = AtomicU64::new(0);
+
 #[cfg(target_os = "macos")]
 tauri_panel! {
     panel!(RecordingOverlayPanel {
@@ -292,6 +301,7 @@ pub fn show_recording_overlay(app_handle: &AppHandle) {
                 .set_position(tauri::Position::Logical(tauri::LogicalPosition { x, y }));
         }
 
+        OVERLAY_GENERATION.fetch_add(1, Ordering::SeqCst);
         let _ = overlay_window.show();
 
         // On Windows, aggressively re-assert "topmost" in the native Z-order after showing
@@ -314,6 +324,7 @@ pub fn show_transcribing_overlay(app_handle: &AppHandle) {
     update_overlay_position(app_handle);
 
     if let Some(overlay_window) = app_handle.get_webview_window("recording_overlay") {
+        OVERLAY_GENERATION.fetch_add(1, Ordering::SeqCst);
         let _ = overlay_window.show();
 
         // On Windows, aggressively re-assert "topmost" in the native Z-order after showing
@@ -325,6 +336,33 @@ pub fn show_transcribing_overlay(app_handle: &AppHandle) {
     }
 }
 
+/// Tells the user a transcription was copied to the clipboard because the paste
+/// was blocked, then hides the overlay after a few seconds. The main window is
+/// usually hidden at this point, so this is shown even when the overlay is
+/// turned off for recording.
+pub fn show_paste_blocked_overlay(app_handle: &AppHandle) {
+    update_overlay_position(app_handle);
+
+    if let Some(overlay_window) = app_handle.get_webview_window("recording_overlay") {
+        let generation = OVERLAY_GENERATION.fetch_add(1, Ordering::SeqCst) + 1;
+        let _ = overlay_window.show();
+
+        #[cfg(target_os = "windows")]
+        force_overlay_topmost(&overlay_window);
+
+        let _ = overlay_window.emit("show-overlay", "paste_blocked");
+
+        // Leave the notice up unless a new recording has taken over the overlay
+        let app_handle = app_handle.clone();
+        std::thread::spawn(move || {
+            std::thread::sleep(PASTE_BLOCKED_NOTICE_DURATION);
+            if OVERLAY_GENERATION.load(Ordering::SeqCst) == generation {
+                hide_recording_overlay(&app_handle);
+            }
+        });
+    }
+}
+
 /// Updates the overlay window position based on current settings
 pub fn update_overlay_position(app_handle: &AppHandle) {
     if let Some(overlay_window) = app_handle.get_webview_window("recording_overlay") {
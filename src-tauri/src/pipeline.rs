@@ -0,0 +1,67 @@
+//! Transcription pipeline state
+//!
+//! Actions record the stage they are about to run. Crash reports attach the
+//! stage that was active, and anything that must not disturb a transcription in
+//! progress (such as rebuilding the tray menu) checks `is_idle` first.
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum PipelineStage {
+    Idle,
+    Recording,
+    /// Local inference or a request to the STT API
+    Transcribing,
+    PostProcessing,
+    Pasting,
+}
+
+impl PipelineStage {
+    pub fn from_u8(value: u8) -> Self {
+        match value {
+            1 => PipelineStage::Recording,
+            2 => PipelineStage::Transcribing,
+            3 => PipelineStage::PostProcessing,
+            4 => PipelineStage::Pasting,
+            _ => PipelineStage::Idle,
+        }
+    }
+}
+
+// A plain atomic so the native crash handler can read it from a signal handler
+static STAGE: AtomicU8 = AtomicU8::new(PipelineStage::Idle as u8);
+
+/// Record the pipeline stage that is about to run.
+pub fn set_stage(stage: PipelineStage) {
+    STAGE.store(stage as u8, Ordering::Relaxed);
+}
+
+pub fn current_stage() -> PipelineStage {
+    PipelineStage::from_u8(STAGE.load(Ordering::Relaxed))
+}
+
+/// Whether no recording or transcription is in progress.
+pub fn is_idle() -> bool {
+    current_stage() == PipelineStage::Idle
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stage_round_trips() {
+        for stage in [
+            PipelineStage::Idle,
+            PipelineStage::Recording,
+            PipelineStage::Transcribing,
+            PipelineStage::PostProcessing,
+            PipelineStage::Pasting,
+        ] {
+            assert_eq!(PipelineStage::from_u8(stage as u8), stage);
+        }
+    }
+}
@@ -0,0 +1,257 @@
+//! Generic HTTP "send to" connectors
+//!
+//! Connectors are user-defined HTTP request templates (method, URL, headers and body)
+//! that forward a transcript to an external service such as a Notion integration,
+//! a Google Apps Script web app or a ticketing system's webhook.
+
+use crate::settings::{get_settings, HttpMethod, SendToConnector};
+use log::{debug, error, info};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE, USER_AGENT};
+use tauri::AppHandle;
+
+/// Placeholder that gets replaced with the transcript.
+pub const TEXT_PLACEHOLDER: &str = "{text}";
+
+/// Substitute the transcript into a URL template, percent-encoding it.
+fn render_url(template: &str, text: &str) -> String {
+    template.replace(TEXT_PLACEHOLDER, &percent_encode(text))
+}
+
+/// Substitute the transcript into a body template. When the connector sends JSON the
+/// transcript is escaped so it can be placed inside a JSON string literal,
+/// e.g. `{"content": "{text}"}`.
+fn render_body(template: &str, text: &str, is_json: bool) -> String {
+    if !is_json {
+        return template.replace(TEXT_PLACEHOLDER, text);
+    }
+
+    let quoted = serde_json::to_string(text).unwrap_or_default();
+    let escaped = quoted
+        .strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .unwrap_or(&quoted);
+    template.replace(TEXT_PLACEHOLDER, escaped)
+}
+
+fn percent_encode(text: &str) -> String {
+    let mut encoded = String::with_capacity(text.len());
+    for byte in text.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+/// Host of a connector URL, safe to write to the log.
+fn redacted_host(url: &str) -> String {
+    reqwest::Url::parse(url)
+        .ok()
+        .and_then(|url| url.host_str().map(str::to_string))
+        .unwrap_or_else(|| "<invalid url>".to_string())
+}
+
+fn has_json_content_type(connector: &SendToConnector) -> bool {
+    connector.headers.iter().any(|header| {
+        header.name.eq_ignore_ascii_case(CONTENT_TYPE.as_str())
+            && header.value.to_ascii_lowercase().contains("json")
+    })
+}
+
+/// Whether the body template is JSON with the placeholder inside string literals,
+/// so the transcript has to be escaped regardless of the headers.
+fn is_json_template(template: &str) -> bool {
+    let trimmed = template.trim_start();
+    (trimmed.starts_with('{') || trimmed.starts_with('['))
+        && serde_json::from_str::<serde_json::Value>(&template.replace(TEXT_PLACEHOLDER, ""))
+            .is_ok()
+}
+
+fn build_headers(connector: &SendToConnector) -> Result<HeaderMap, String> {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        USER_AGENT,
+        HeaderValue::from_static("Handy/1.0 (+https://github.com/cjpais/Handy)"),
+    );
+
+    for header in &connector.headers {
+        if header.name.trim().is_empty() {
+            continue;
+        }
+        let name = HeaderName::from_bytes(header.name.trim().as_bytes())
+            .map_err(|e| format!("Invalid header name '{}': {}", header.name, e))?;
+        let value = HeaderValue::from_str(&header.value)
+            .map_err(|e| format!("Invalid value for header '{}': {}", header.name, e))?;
+        headers.insert(name, value);
+    }
+
+    Ok(headers)
+}
+
+/// Validate a connector before it is saved.
+pub fn validate_connector(connector: &SendToConnector) -> Result<(), String> {
+    if connector.name.trim().is_empty() {
+        return Err("Connector name cannot be empty".to_string());
+    }
+
+    let url = connector.url.trim();
+    if !(url.starts_with("http://") || url.starts_with("https://")) {
+        return Err("Connector URL must start with http:// or https://".to_string());
+    }
+
+    build_headers(connector).map(|_| ())
+}
+
+/// Send a transcript to a single connector.
+pub async fn send_to_connector(connector: &SendToConnector, text: &str) -> Result<(), String> {
+    let url = render_url(connector.url.trim(), text);
+    let headers = build_headers(connector)?;
+
+    let client = reqwest::Client::builder()
+        .default_headers(headers)
+        .build()
+        .map_err(|e| format!("Failed to build HTTP client: {}", e))?;
+
+    let mut request = match connector.method {
+        HttpMethod::Get => client.get(&url),
+        HttpMethod::Post => client.post(&url),
+        HttpMethod::Put => client.put(&url),
+        HttpMethod::Patch => client.patch(&url),
+    };
+
+    if connector.method != HttpMethod::Get && !connector.body_template.is_empty() {
+        let is_json =
+            has_json_content_type(connector) || is_json_template(&connector.body_template);
+        let body = render_body(&connector.body_template, text, is_json);
+        request = request.body(body);
+    }
+
+    // The URL may carry tokens in its path or query, so only the host is logged
+    debug!(
+        "Sending transcript to connector '{}' ({:?} {})",
+        connector.name,
+        connector.method,
+        redacted_host(&url)
+    );
+
+    let response = request
+        .send()
+        .await
+        .map_err(|e| format!("Failed to send request: {}", e.without_url()))?;
+
+    let status = response.status();
+    if !status.is_success() {
+        let body = response
+            .text()
+            .await
+            .unwrap_or_else(|_| "Failed to read response".to_string());
+        return Err(format!("Connector returned {}: {}", status, body));
+    }
+
+    info!("Sent transcript to connector '{}'", connector.name);
+    Ok(())
+}
+
+/// Send a transcript to every connector that has `auto_send` enabled.
+/// Requests run in the background and failures are only logged.
+pub fn send_to_auto_connectors(app: &AppHandle, text: &str) {
+    let settings = get_settings(app);
+
+    for connector in settings
+        .send_to_connectors
+        .into_iter()
+        .filter(|connector| connector.auto_send)
+    {
+        let text = text.to_string();
+        tauri::async_runtime::spawn(async move {
+            if let Err(e) = send_to_connector(&connector, &text).await {
+                error!(
+                    "Failed to auto-send transcript to connector '{}': {}",
+                    connector.name, e
+                );
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::settings::HttpHeader;
+
+    fn build_connector(headers: Vec<(&str, &str)>) -> SendToConnector {
+        SendToConnector {
+            id: "connector_1".to_string(),
+            name: "Webhook".to_string(),
+            method: HttpMethod::Post,
+            url: "https://example.com/hook".to_string(),
+            headers: headers
+                .into_iter()
+                .map(|(name, value)| HttpHeader {
+                    name: name.to_string(),
+                    value: value.to_string(),
+                })
+                .collect(),
+            body_template: String::new(),
+            auto_send: false,
+        }
+    }
+
+    #[test]
+    fn render_body_escapes_json() {
+        let body = render_body(r#"{"content": "{text}"}"#, "say \"hi\"\nnow", true);
+        assert_eq!(body, r#"{"content": "say \"hi\"\nnow"}"#);
+        assert!(serde_json::from_str::<serde_json::Value>(&body).is_ok());
+    }
+
+    #[test]
+    fn render_body_keeps_plain_text() {
+        let body = render_body("Note: {text}", "say \"hi\"", false);
+        assert_eq!(body, "Note: say \"hi\"");
+    }
+
+    #[test]
+    fn render_url_percent_encodes_text() {
+        let url = render_url("https://example.com/add?q={text}", "a b&c/é");
+        assert_eq!(url, "https://example.com/add?q=a%20b%26c%2F%C3%A9");
+    }
+
+    #[test]
+    fn redacted_host_drops_path_and_query() {
+        let host = redacted_host("https://hooks.example.com/services/T000/B000/secret?token=abc");
+        assert_eq!(host, "hooks.example.com");
+        assert_eq!(redacted_host("not a url"), "<invalid url>");
+    }
+
+    #[test]
+    fn detects_json_content_type_case_insensitively() {
+        let connector = build_connector(vec![("content-type", "Application/JSON")]);
+        assert!(has_json_content_type(&connector));
+
+        let connector = build_connector(vec![("Content-Type", "text/plain")]);
+        assert!(!has_json_content_type(&connector));
+    }
+
+    #[test]
+    fn detects_json_body_templates() {
+        assert!(is_json_template(r#"{"content": "{text}"}"#));
+        assert!(is_json_template(r#" [{"text": "{text}"}]"#));
+        assert!(!is_json_template("Note: {text}"));
+        assert!(!is_json_template("{text}"));
+    }
+
+    #[test]
+    fn validate_rejects_bad_urls_and_headers() {
+        let mut connector = build_connector(vec![]);
+        assert!(validate_connector(&connector).is_ok());
+
+        connector.url = "ftp://example.com".to_string();
+        assert!(validate_connector(&connector).is_err());
+
+        let connector = build_connector(vec![("Bad Header", "value")]);
+        assert!(validate_connector(&connector).is_err());
+    }
+}
@@ -141,6 +141,28 @@ pub enum ClipboardHandling {
     CopyToClipboard,
 }
 
+/// Tool used to inject keystrokes and text on Linux. `Auto` picks one based on the
+/// session type (wtype/dotool/ydotool on Wayland, xdotool on X11).
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum TypingTool {
+    Auto,
+    Wtype,
+    Kwtype,
+    Dotool,
+    Ydotool,
+    Xdotool,
+    Enigo,
+}
+
+/// Which releases the update checker offers. `Beta` also includes pre-releases.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
+#[serde(rename_all = "snake_case")]
+pub enum UpdateChannel {
+    Stable,
+    Beta,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
 #[serde(rename_all = "snake_case")]
 pub enum RecordingRetentionPeriod {
@@ -156,6 +178,39 @@ pub enum RecordingRetentionPeriod {
 pub enum KeyboardImplementation {
     Tauri,
     HandyKeys,
+    Wayland,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Type)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum HttpMethod {
+    Get,
+    Post,
+    Put,
+    Patch,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Type)]
+pub struct HttpHeader {
+    pub name: String,
+    pub value: String,
+}
+
+/// A user-defined "send to" destination described as an HTTP request template.
+/// `{text}` in the URL or body is replaced with the transcript when the request is sent.
+#[derive(Serialize, Deserialize, Debug, Clone, Type)]
+pub struct SendToConnector {
+    pub id: String,
+    pub name: String,
+    pub method: HttpMethod,
+    pub url: String,
+    #[serde(default)]
+    pub headers: Vec<HttpHeader>,
+    #[serde(default)]
+    pub body_template: String,
+    /// Send every completed transcription to this connector automatically.
+    #[serde(default)]
+    pub auto_send: bool,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, Type)]
@@ -213,14 +268,33 @@ impl Default for SttApiSettings {
 impl Default for KeyboardImplementation {
     fn default() -> Self {
         // Default to HandyKeys only on macOS where it's well-tested.
-        // Windows and Linux use Tauri by default (handy-keys not sufficiently tested yet).
+        // Windows and Linux use Tauri by default (handy-keys not sufficiently tested yet),
+        // except Wayland sessions where only the portal/evdev implementation receives keys.
         #[cfg(target_os = "macos")]
         return KeyboardImplementation::HandyKeys;
-        #[cfg(not(target_os = "macos"))]
+        #[cfg(target_os = "linux")]
+        return if crate::utils::is_wayland() {
+            KeyboardImplementation::Wayland
+        } else {
+            KeyboardImplementation::Tauri
+        };
+        #[cfg(not(any(target_os = "macos", target_os = "linux")))]
         return KeyboardImplementation::Tauri;
     }
 }
 
+impl Default for TypingTool {
+    fn default() -> Self {
+        TypingTool::Auto
+    }
+}
+
+impl Default for UpdateChannel {
+    fn default() -> Self {
+        UpdateChannel::Stable
+    }
+}
+
 impl Default for ModelUnloadTimeout {
     fn default() -> Self {
         ModelUnloadTimeout::Never
@@ -307,8 +381,14 @@ pub struct AppSettings {
     pub start_hidden: bool,
     #[serde(default = "default_autostart_enabled")]
     pub autostart_enabled: bool,
+    #[serde(default)]
+    pub autostart_start_hidden: bool,
     #[serde(default = "default_update_checks_enabled")]
     pub update_checks_enabled: bool,
+    #[serde(default)]
+    pub update_channel: UpdateChannel,
+    #[serde(default)]
+    pub crash_reporting_enabled: bool,
     #[serde(default = "default_model")]
     pub selected_model: String,
     #[serde(default = "default_always_on_microphone")]
@@ -370,7 +450,11 @@ pub struct AppSettings {
     #[serde(default = "default_paste_delay_ms")]
     pub paste_delay_ms: u64,
     #[serde(default)]
+    pub typing_tool: TypingTool,
+    #[serde(default)]
     pub stt_api: SttApiSettings,
+    #[serde(default)]
+    pub send_to_connectors: Vec<SendToConnector>,
 }
 
 fn default_model() -> String {
@@ -691,7 +775,10 @@ pub fn get_default_settings() -> AppSettings {
         sound_theme: default_sound_theme(),
         start_hidden: default_start_hidden(),
         autostart_enabled: default_autostart_enabled(),
+        autostart_start_hidden: false,
         update_checks_enabled: default_update_checks_enabled(),
+        update_channel: UpdateChannel::default(),
+        crash_reporting_enabled: false,
         selected_model: "".to_string(),
         always_on_microphone: false,
         selected_microphone: None,
@@ -722,7 +809,9 @@ pub fn get_default_settings() -> AppSettings {
         experimental_enabled: false,
         keyboard_implementation: KeyboardImplementation::default(),
         paste_delay_ms: default_paste_delay_ms(),
+        typing_tool: TypingTool::default(),
         stt_api: SttApiSettings::default(),
+        send_to_connectors: Vec::new(),
     }
 }
 
@@ -768,6 +857,12 @@ impl AppSettings {
             .iter_mut()
             .find(|provider| provider.id == provider_id)
     }
+
+    pub fn send_to_connector(&self, connector_id: &str) -> Option<&SendToConnector> {
+        self.send_to_connectors
+            .iter()
+            .find(|connector| connector.id == connector_id)
+    }
 }
 
 pub fn load_or_create_app_settings(app: &AppHandle) -> AppSettings {
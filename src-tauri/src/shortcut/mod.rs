@@ -5,6 +5,7 @@
 //!
 //! - `tauri`: Uses Tauri's built-in global-shortcut plugin
 //! - `handy_keys`: Uses the handy-keys library for more control
+//! - `wayland`: Uses the XDG GlobalShortcuts portal (or evdev) on Wayland
 //!
 //! The active implementation is determined by the `keyboard_implementation`
 //! setting and can be changed at runtime.
@@ -12,6 +13,7 @@
 mod handler;
 pub mod handy_keys;
 mod tauri_impl;
+mod wayland;
 
 use log::{error, info, warn};
 use serde::Serialize;
@@ -21,8 +23,8 @@ use tauri_plugin_autostart::ManagerExt;
 
 use crate::settings::{
     self, get_settings, ClipboardHandling, KeyboardImplementation, LLMPrompt, OverlayPosition,
-    PasteMethod, ShortcutBinding, SoundTheme, APPLE_INTELLIGENCE_DEFAULT_MODEL_ID,
-    APPLE_INTELLIGENCE_PROVIDER_ID,
+    PasteMethod, ShortcutBinding, SoundTheme, TypingTool, UpdateChannel,
+    APPLE_INTELLIGENCE_DEFAULT_MODEL_ID, APPLE_INTELLIGENCE_PROVIDER_ID,
 };
 use crate::tray;
 
@@ -48,6 +50,18 @@ pub fn init_shortcuts(app: &AppHandle) {
                 settings.keyboard_implementation = KeyboardImplementation::Tauri;
                 settings::write_settings(app, settings);
 
+                tauri_impl::init_shortcuts(app);
+            }
+        }
+        KeyboardImplementation::Wayland => {
+            if let Err(e) = wayland::init_shortcuts(app, KeyboardImplementation::Tauri) {
+                error!("Failed to initialize Wayland shortcuts: {}", e);
+                warn!("Falling back to Tauri global shortcut implementation and saving fallback to settings");
+
+                let mut settings = settings::get_settings(app);
+                settings.keyboard_implementation = KeyboardImplementation::Tauri;
+                settings::write_settings(app, settings);
+
                 tauri_impl::init_shortcuts(app);
             }
         }
@@ -60,6 +74,7 @@ pub fn register_cancel_shortcut(app: &AppHandle) {
     match settings.keyboard_implementation {
         KeyboardImplementation::Tauri => tauri_impl::register_cancel_shortcut(app),
         KeyboardImplementation::HandyKeys => handy_keys::register_cancel_shortcut(app),
+        KeyboardImplementation::Wayland => wayland::register_cancel_shortcut(app),
     }
 }
 
@@ -69,6 +84,7 @@ pub fn unregister_cancel_shortcut(app: &AppHandle) {
     match settings.keyboard_implementation {
         KeyboardImplementation::Tauri => tauri_impl::unregister_cancel_shortcut(app),
         KeyboardImplementation::HandyKeys => handy_keys::unregister_cancel_shortcut(app),
+        KeyboardImplementation::Wayland => wayland::unregister_cancel_shortcut(app),
     }
 }
 
@@ -78,6 +94,7 @@ pub fn register_shortcut(app: &AppHandle, binding: ShortcutBinding) -> Result<()
     match settings.keyboard_implementation {
         KeyboardImplementation::Tauri => tauri_impl::register_shortcut(app, binding),
         KeyboardImplementation::HandyKeys => handy_keys::register_shortcut(app, binding),
+        KeyboardImplementation::Wayland => wayland::register_shortcut(app, binding),
     }
 }
 
@@ -87,6 +104,7 @@ pub fn unregister_shortcut(app: &AppHandle, binding: ShortcutBinding) -> Result<
     match settings.keyboard_implementation {
         KeyboardImplementation::Tauri => tauri_impl::unregister_shortcut(app, binding),
         KeyboardImplementation::HandyKeys => handy_keys::unregister_shortcut(app, binding),
+        KeyboardImplementation::Wayland => wayland::unregister_shortcut(app, binding),
     }
 }
 
@@ -164,7 +182,8 @@ pub fn change_binding(
     }
 
     // Validate the new shortcut for the current keyboard implementation
-    if let Err(e) = validate_shortcut_for_implementation(&binding, settings.keyboard_implementation)
+    if let Err(e) =
+        validate_shortcut_for_implementation(&app, &binding, settings.keyboard_implementation)
     {
         warn!("change_binding validation error: {}", e);
         return Err(e);
@@ -280,15 +299,18 @@ pub fn change_keyboard_implementation_setting(
     settings.keyboard_implementation = new_impl;
     settings::write_settings(&app, settings);
 
-    // Initialize new implementation if needed (HandyKeys needs state)
-    if new_impl == KeyboardImplementation::HandyKeys {
-        if initialize_handy_keys_with_rollback(&app)? {
-            // Shortcuts already registered during init
-            return Ok(ImplementationChangeResult {
-                success: true,
-                reset_bindings: vec![],
-            });
-        }
+    // Initialize new implementation if needed (HandyKeys and Wayland need state)
+    let initialized = match new_impl {
+        KeyboardImplementation::HandyKeys => initialize_handy_keys_with_rollback(&app)?,
+        KeyboardImplementation::Wayland => initialize_wayland_with_rollback(&app, current_impl)?,
+        KeyboardImplementation::Tauri => false,
+    };
+    if initialized {
+        // Shortcuts already registered during init
+        return Ok(ImplementationChangeResult {
+            success: true,
+            reset_bindings: vec![],
+        });
     }
 
     // Register all shortcuts with new implementation, resetting invalid ones
@@ -320,6 +342,7 @@ pub fn get_keyboard_implementation(app: AppHandle) -> String {
     match settings.keyboard_implementation {
         KeyboardImplementation::Tauri => "tauri".to_string(),
         KeyboardImplementation::HandyKeys => "handy_keys".to_string(),
+        KeyboardImplementation::Wayland => "wayland".to_string(),
     }
 }
 
@@ -329,12 +352,14 @@ pub fn get_keyboard_implementation(app: AppHandle) -> String {
 
 /// Validate a shortcut for a specific implementation
 fn validate_shortcut_for_implementation(
+    app: &AppHandle,
     raw: &str,
     implementation: KeyboardImplementation,
 ) -> Result<(), String> {
     match implementation {
         KeyboardImplementation::Tauri => tauri_impl::validate_shortcut(raw),
         KeyboardImplementation::HandyKeys => handy_keys::validate_shortcut(raw),
+        KeyboardImplementation::Wayland => wayland::validate_shortcut(app, raw),
     }
 }
 
@@ -343,6 +368,7 @@ fn parse_keyboard_implementation(s: &str) -> KeyboardImplementation {
     match s {
         "tauri" => KeyboardImplementation::Tauri,
         "handy_keys" => KeyboardImplementation::HandyKeys,
+        "wayland" => KeyboardImplementation::Wayland,
         other => {
             warn!(
                 "Invalid keyboard implementation '{}', defaulting to tauri",
@@ -366,6 +392,7 @@ fn unregister_all_shortcuts(app: &AppHandle, implementation: KeyboardImplementat
         let result = match implementation {
             KeyboardImplementation::Tauri => tauri_impl::unregister_shortcut(app, binding),
             KeyboardImplementation::HandyKeys => handy_keys::unregister_shortcut(app, binding),
+            KeyboardImplementation::Wayland => wayland::unregister_shortcut(app, binding),
         };
 
         if let Err(e) = result {
@@ -405,7 +432,7 @@ fn register_all_shortcuts_for_implementation(
 
         // Validate the shortcut for the target implementation
         if let Err(e) =
-            validate_shortcut_for_implementation(&binding.current_binding, implementation)
+            validate_shortcut_for_implementation(app, &binding.current_binding, implementation)
         {
             info!(
                 "Shortcut '{}' ({}) is invalid for {:?}: {}. Resetting to default.",
@@ -424,6 +451,7 @@ fn register_all_shortcuts_for_implementation(
         let result = match implementation {
             KeyboardImplementation::Tauri => tauri_impl::register_shortcut(app, binding),
             KeyboardImplementation::HandyKeys => handy_keys::register_shortcut(app, binding),
+            KeyboardImplementation::Wayland => wayland::register_shortcut(app, binding),
         };
 
         if let Err(e) = result {
@@ -465,6 +493,72 @@ fn initialize_handy_keys_with_rollback(app: &AppHandle) -> Result<bool, String>
     Ok(true)
 }
 
+/// Initialize Wayland shortcuts if not already running, with rollback on failure.
+/// Failures after the manager has started switch back to `previous` instead.
+fn initialize_wayland_with_rollback(
+    app: &AppHandle,
+    previous: KeyboardImplementation,
+) -> Result<bool, String> {
+    if let Some(state) = app.try_state::<wayland::WaylandShortcutState>() {
+        if !state.has_failed() {
+            return Ok(false); // Already initialized, caller should continue
+        }
+    }
+
+    if let Err(e) = wayland::init_shortcuts(app, previous) {
+        error!("Failed to initialize Wayland shortcuts: {}", e);
+        // Rollback to Tauri
+        let mut settings = settings::get_settings(app);
+        settings.keyboard_implementation = KeyboardImplementation::Tauri;
+        settings::write_settings(app, settings);
+        tauri_impl::init_shortcuts(app);
+        return Err(format!(
+            "Failed to initialize Wayland shortcuts: {}. Reverted to Tauri.",
+            e
+        ));
+    }
+
+    // init_shortcuts already registered shortcuts
+    Ok(true)
+}
+
+/// Called by the Wayland manager when neither of its backends could start.
+/// Switches back to `previous` and tells the frontend why.
+fn revert_failed_wayland_backend(app: &AppHandle, previous: KeyboardImplementation, error: &str) {
+    let mut settings = settings::get_settings(app);
+    if settings.keyboard_implementation != KeyboardImplementation::Wayland {
+        // The user already switched to another implementation
+        return;
+    }
+
+    warn!("Reverting keyboard implementation to {:?}", previous);
+    settings.keyboard_implementation = previous;
+    settings::write_settings(app, settings);
+
+    let reset_bindings = match previous {
+        KeyboardImplementation::HandyKeys => match initialize_handy_keys_with_rollback(app) {
+            Ok(true) => vec![],
+            Ok(false) => register_all_shortcuts_for_implementation(app, previous),
+            // Already reverted to Tauri with its shortcuts registered
+            Err(e) => {
+                error!("{}", e);
+                vec![]
+            }
+        },
+        _ => register_all_shortcuts_for_implementation(app, KeyboardImplementation::Tauri),
+    };
+
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({
+            "setting": "keyboard_implementation",
+            "value": settings::get_settings(app).keyboard_implementation,
+            "reset_bindings": reset_bindings
+        }),
+    );
+    let _ = app.emit("shortcut-backend-failed", error);
+}
+
 // ============================================================================
 // General Settings Commands
 // ============================================================================
@@ -601,11 +695,12 @@ pub fn change_autostart_setting(app: AppHandle, enabled: bool) -> Result<(), Str
 
     // Apply the autostart setting immediately
     let autostart_manager = app.autolaunch();
-    if enabled {
-        let _ = autostart_manager.enable();
+    let result = if enabled {
+        autostart_manager.enable()
     } else {
-        let _ = autostart_manager.disable();
-    }
+        autostart_manager.disable()
+    };
+    result.map_err(|e| format!("Failed to update launch-at-login entry: {}", e))?;
 
     // Notify frontend
     let _ = app.emit(
@@ -619,6 +714,24 @@ pub fn change_autostart_setting(app: AppHandle, enabled: bool) -> Result<(), Str
     Ok(())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub fn change_autostart_start_hidden_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.autostart_start_hidden = enabled;
+    settings::write_settings(&app, settings);
+
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({
+            "setting": "autostart_start_hidden",
+            "value": enabled
+        }),
+    );
+
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn change_update_checks_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
@@ -633,6 +746,69 @@ pub fn change_update_checks_setting(app: AppHandle, enabled: bool) -> Result<(),
             "value": enabled
         }),
     );
+    if enabled {
+        crate::updater::wake_background_checks(&app);
+    } else {
+        crate::updater::clear_pending_update(&app);
+    }
+
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_update_channel_setting(app: AppHandle, channel: String) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    let parsed = match channel.as_str() {
+        "stable" => UpdateChannel::Stable,
+        "beta" => UpdateChannel::Beta,
+        other => {
+            warn!("Invalid update channel '{}', defaulting to stable", other);
+            UpdateChannel::Stable
+        }
+    };
+    settings.update_channel = parsed;
+    settings::write_settings(&app, settings);
+
+    // An update found on the previous channel may not apply to the new one
+    crate::updater::clear_pending_update(&app);
+
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({
+            "setting": "update_channel",
+            "value": parsed
+        }),
+    );
+
+    crate::updater::wake_background_checks(&app);
+
+    Ok(())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn change_crash_reporting_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    settings.crash_reporting_enabled = enabled;
+    settings::write_settings(&app, settings);
+    crate::crash::set_enabled(enabled);
+
+    let _ = app.emit(
+        "settings-changed",
+        serde_json::json!({
+            "setting": "crash_reporting_enabled",
+            "value": enabled
+        }),
+    );
+
+    // Send anything collected before consent was given
+    if enabled {
+        let app = app.clone();
+        tauri::async_runtime::spawn(async move {
+            crate::crash::upload_pending_reports(&app).await;
+        });
+    }
 
     Ok(())
 }
@@ -698,6 +874,28 @@ pub fn change_clipboard_handling_setting(app: AppHandle, handling: String) -> Re
     Ok(())
 }
 
+#[tauri::command]
+#[specta::specta]
+pub fn change_typing_tool_setting(app: AppHandle, tool: String) -> Result<(), String> {
+    let mut settings = settings::get_settings(&app);
+    let parsed = match tool.as_str() {
+        "auto" => TypingTool::Auto,
+        "wtype" => TypingTool::Wtype,
+        "kwtype" => TypingTool::Kwtype,
+        "dotool" => TypingTool::Dotool,
+        "ydotool" => TypingTool::Ydotool,
+        "xdotool" => TypingTool::Xdotool,
+        "enigo" => TypingTool::Enigo,
+        other => {
+            warn!("Invalid typing tool '{}', defaulting to auto", other);
+            TypingTool::Auto
+        }
+    };
+    settings.typing_tool = parsed;
+    settings::write_settings(&app, settings);
+    Ok(())
+}
+
 #[tauri::command]
 #[specta::specta]
 pub fn change_post_process_enabled_setting(app: AppHandle, enabled: bool) -> Result<(), String> {
@@ -0,0 +1,516 @@
+//! Wayland keyboard shortcut implementation
+//!
+//! Wayland compositors don't let clients grab keys globally the way X11 does,
+//! so neither the Tauri global-shortcut plugin nor handy-keys receive events
+//! there. This implementation uses the XDG desktop portal instead:
+//!
+//! - `GlobalShortcuts` portal (GNOME 48+, KDE Plasma 5.27+): the compositor owns
+//!   the key grab and notifies us over D-Bus when a shortcut is activated and
+//!   deactivated, which gives us proper push-to-talk press/release events.
+//! - evdev fallback: when the portal isn't available, keyboards are read directly
+//!   from `/dev/input`. This only works if the user may read input devices
+//!   (usually by being in the `input` group).
+//!
+//! ## Architecture
+//!
+//! Bindings are parsed when registered and kept in a shared map (binding id ->
+//! shortcut). A manager task owns the active backend; registering or
+//! unregistering updates the map and asks the manager to rebind. For the portal,
+//! rebind requests arriving close together are coalesced into one session
+//! update, since every new session may prompt the user on GNOME. The evdev
+//! fallback resolves the map to key codes on each rebind.
+//!
+//! Starting a backend may wait on a confirmation dialog, so initialization
+//! doesn't block on it. If neither backend starts, the manager switches back to
+//! the previous implementation and emits `shortcut-backend-failed`.
+//!
+//! The cancel shortcut is only active with the evdev backend. A portal shortcut
+//! grabs its key for the whole desktop, so binding Escape permanently would
+//! swallow it everywhere, and binding it for each recording would re-prompt.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+#[cfg(target_os = "linux")]
+use std::time::Duration;
+use tauri::{AppHandle, Manager};
+
+use crate::settings::{KeyboardImplementation, ShortcutBinding};
+
+#[cfg(target_os = "linux")]
+use crate::settings;
+#[cfg(target_os = "linux")]
+use log::{error, info, warn};
+
+#[cfg(target_os = "linux")]
+mod evdev_listener;
+#[cfg(target_os = "linux")]
+mod portal;
+
+/// A registered shortcut, parsed once when it is registered
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+struct WaylandBinding {
+    raw: String,
+    shortcut: ParsedShortcut,
+}
+
+/// Shared map of binding id -> shortcut
+type BindingMap = Arc<Mutex<HashMap<String, WaylandBinding>>>;
+
+/// The backend the manager task is running
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+enum Backend {
+    Starting,
+    Portal,
+    Evdev,
+    /// Neither backend could be started
+    Failed,
+}
+
+type BackendStatus = Arc<Mutex<Backend>>;
+
+/// Binding id of the dynamically registered cancel shortcut
+const CANCEL_BINDING_ID: &str = "cancel";
+
+/// Time to wait for further rebind requests before updating the portal session
+#[cfg(target_os = "linux")]
+const REBIND_DEBOUNCE: Duration = Duration::from_millis(250);
+
+/// Commands sent to the manager task
+enum ManagerCommand {
+    /// The binding map changed and the active backend should pick it up
+    Rebind,
+    Shutdown,
+}
+
+/// State for the Wayland shortcut manager
+pub struct WaylandShortcutState {
+    bindings: BindingMap,
+    backend: BackendStatus,
+    command_sender: Mutex<tokio::sync::mpsc::UnboundedSender<ManagerCommand>>,
+}
+
+impl WaylandShortcutState {
+    #[cfg(target_os = "linux")]
+    fn new(
+        app: AppHandle,
+        initial: HashMap<String, WaylandBinding>,
+        fallback: KeyboardImplementation,
+    ) -> Self {
+        let bindings: BindingMap = Arc::new(Mutex::new(initial));
+        let backend: BackendStatus = Arc::new(Mutex::new(Backend::Starting));
+        let command_sender = spawn_manager(app, &bindings, &backend, fallback);
+
+        Self {
+            bindings,
+            backend,
+            command_sender: Mutex::new(command_sender),
+        }
+    }
+
+    /// Start a new manager task after the previous one failed
+    #[cfg(target_os = "linux")]
+    fn restart(
+        &self,
+        app: AppHandle,
+        initial: HashMap<String, WaylandBinding>,
+        fallback: KeyboardImplementation,
+    ) -> Result<(), String> {
+        *self
+            .bindings
+            .lock()
+            .map_err(|_| "Failed to lock Wayland bindings")? = initial;
+        set_backend(&self.backend, Backend::Starting);
+        let command_sender = spawn_manager(app, &self.bindings, &self.backend, fallback);
+        *self
+            .command_sender
+            .lock()
+            .map_err(|_| "Failed to lock Wayland shortcut manager")? = command_sender;
+        Ok(())
+    }
+
+    fn backend(&self) -> Backend {
+        self.backend
+            .lock()
+            .map(|backend| *backend)
+            .unwrap_or(Backend::Failed)
+    }
+
+    /// Whether neither backend could be started
+    pub fn has_failed(&self) -> bool {
+        self.backend() == Backend::Failed
+    }
+
+    /// Parse a shortcut and check the running backend can listen for it
+    fn validate(&self, raw: &str) -> Result<ParsedShortcut, String> {
+        let shortcut = parse_binding(raw)?;
+        #[cfg(target_os = "linux")]
+        if self.backend() == Backend::Evdev && !evdev_listener::supports_key(&shortcut.key) {
+            return Err(format!(
+                "The '{}' key is not supported by the evdev shortcut backend",
+                shortcut.key
+            ));
+        }
+        Ok(shortcut)
+    }
+
+    /// Register a shortcut binding
+    pub fn register(&self, binding: &ShortcutBinding) -> Result<(), String> {
+        let shortcut = self.validate(&binding.current_binding)?;
+        let previous = self
+            .bindings
+            .lock()
+            .map_err(|_| "Failed to lock Wayland bindings")?
+            .insert(
+                binding.id.clone(),
+                WaylandBinding {
+                    raw: binding.current_binding.clone(),
+                    shortcut,
+                },
+            );
+        if previous.is_some_and(|previous| previous.raw == binding.current_binding) {
+            return Ok(());
+        }
+        self.request_rebind()
+    }
+
+    /// Unregister a shortcut binding
+    pub fn unregister(&self, binding: &ShortcutBinding) -> Result<(), String> {
+        let removed = self
+            .bindings
+            .lock()
+            .map_err(|_| "Failed to lock Wayland bindings")?
+            .remove(&binding.id);
+        if removed.is_some() {
+            self.request_rebind()?;
+        }
+        Ok(())
+    }
+
+    fn request_rebind(&self) -> Result<(), String> {
+        self.command_sender
+            .lock()
+            .map_err(|_| "Failed to lock Wayland shortcut manager")?
+            .send(ManagerCommand::Rebind)
+            .map_err(|_| "Wayland shortcut manager is not running".to_string())
+    }
+}
+
+impl Drop for WaylandShortcutState {
+    fn drop(&mut self) {
+        if let Ok(sender) = self.command_sender.lock() {
+            let _ = sender.send(ManagerCommand::Shutdown);
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn set_backend(status: &BackendStatus, backend: Backend) {
+    if let Ok(mut status) = status.lock() {
+        *status = backend;
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn spawn_manager(
+    app: AppHandle,
+    bindings: &BindingMap,
+    backend: &BackendStatus,
+    fallback: KeyboardImplementation,
+) -> tokio::sync::mpsc::UnboundedSender<ManagerCommand> {
+    let (cmd_tx, cmd_rx) = tokio::sync::mpsc::unbounded_channel();
+    let bindings = Arc::clone(bindings);
+    let backend = Arc::clone(backend);
+    tauri::async_runtime::spawn(async move {
+        manager_task(app, bindings, backend, cmd_rx, fallback).await;
+    });
+    cmd_tx
+}
+
+/// Runs the portal session, falling back to evdev if the portal is unavailable.
+/// If neither backend starts, switches back to `fallback`.
+#[cfg(target_os = "linux")]
+async fn manager_task(
+    app: AppHandle,
+    bindings: BindingMap,
+    backend: BackendStatus,
+    mut cmd_rx: tokio::sync::mpsc::UnboundedReceiver<ManagerCommand>,
+    fallback: KeyboardImplementation,
+) {
+    let portal_error = match portal::run(&app, &bindings, &backend, &mut cmd_rx).await {
+        Ok(()) => return,
+        Err(e) => {
+            warn!(
+                "GlobalShortcuts portal unavailable ({}), falling back to evdev",
+                e
+            );
+            e
+        }
+    };
+
+    let listener = match evdev_listener::spawn(app.clone(), &bindings) {
+        Ok(listener) => listener,
+        Err(e) => {
+            let message = format!(
+                "GlobalShortcuts portal unavailable ({}) and evdev fallback failed ({})",
+                portal_error, e
+            );
+            error!("{}", message);
+            set_backend(&backend, Backend::Failed);
+            super::revert_failed_wayland_backend(&app, fallback, &message);
+            return;
+        }
+    };
+    set_backend(&backend, Backend::Evdev);
+
+    while let Some(cmd) = cmd_rx.recv().await {
+        match cmd {
+            ManagerCommand::Rebind => listener.rebind(&bindings),
+            ManagerCommand::Shutdown => break,
+        }
+    }
+}
+
+/// Modifier keys understood by both the portal and evdev backends
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Modifier {
+    Ctrl,
+    Shift,
+    Alt,
+    Super,
+}
+
+/// A shortcut string split into modifiers and a main key, e.g. `ctrl+shift+space`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ParsedShortcut {
+    modifiers: Vec<Modifier>,
+    /// Lowercased main key name (e.g. "space", "a", "f5")
+    key: String,
+}
+
+fn parse_shortcut(raw: &str) -> Result<ParsedShortcut, String> {
+    let mut modifiers = Vec::new();
+    let mut key: Option<String> = None;
+
+    for part in raw.split('+').map(|p| p.trim().to_lowercase()) {
+        let modifier = match part.as_str() {
+            "" => return Err(format!("Invalid shortcut '{}'", raw)),
+            "ctrl" | "control" => Some(Modifier::Ctrl),
+            "shift" => Some(Modifier::Shift),
+            "alt" | "option" => Some(Modifier::Alt),
+            "super" | "meta" | "command" | "cmd" | "win" | "windows" => Some(Modifier::Super),
+            "fn" | "function" => {
+                return Err("The 'fn' key is not supported on Wayland".to_string());
+            }
+            _ => None,
+        };
+
+        match modifier {
+            Some(m) if !modifiers.contains(&m) => modifiers.push(m),
+            Some(_) => {}
+            None if key.is_none() => key = Some(part),
+            None => return Err(format!("Shortcut '{}' has more than one main key", raw)),
+        }
+    }
+
+    let key = key.ok_or_else(|| {
+        "Wayland shortcuts must include a main key (letter, number, F-key, etc.) in addition to modifiers".to_string()
+    })?;
+
+    Ok(ParsedShortcut { modifiers, key })
+}
+
+/// Convert a Handy shortcut string into the XDG shortcut trigger format
+/// (e.g. `ctrl+shift+space` -> `CTRL+SHIFT+space`).
+#[cfg(any(target_os = "linux", test))]
+fn to_portal_trigger(shortcut: &ParsedShortcut) -> String {
+    let mut parts: Vec<String> = shortcut
+        .modifiers
+        .iter()
+        .map(|m| {
+            match m {
+                Modifier::Ctrl => "CTRL",
+                Modifier::Shift => "SHIFT",
+                Modifier::Alt => "ALT",
+                Modifier::Super => "LOGO",
+            }
+            .to_string()
+        })
+        .collect();
+
+    // The key part uses XKB keysym names
+    let keysym = match shortcut.key.as_str() {
+        "escape" | "esc" => "Escape".to_string(),
+        "enter" | "return" => "Return".to_string(),
+        "tab" => "Tab".to_string(),
+        "backspace" => "BackSpace".to_string(),
+        "delete" => "Delete".to_string(),
+        "insert" => "Insert".to_string(),
+        "home" => "Home".to_string(),
+        "end" => "End".to_string(),
+        "pageup" => "Page_Up".to_string(),
+        "pagedown" => "Page_Down".to_string(),
+        "up" | "arrowup" => "Up".to_string(),
+        "down" | "arrowdown" => "Down".to_string(),
+        "left" | "arrowleft" => "Left".to_string(),
+        "right" | "arrowright" => "Right".to_string(),
+        key if key.len() > 1 && key.starts_with('f') && key[1..].parse::<u8>().is_ok() => {
+            key.to_uppercase()
+        }
+        key => key.to_string(),
+    };
+    parts.push(keysym);
+
+    parts.join("+")
+}
+
+fn parse_binding(raw: &str) -> Result<ParsedShortcut, String> {
+    if raw.trim().is_empty() {
+        return Err("Shortcut cannot be empty".into());
+    }
+    parse_shortcut(raw)
+}
+
+/// Validate a shortcut string for the Wayland implementation. Once the evdev
+/// fallback is running, keys it has no key code for are rejected as well.
+pub fn validate_shortcut(app: &AppHandle, raw: &str) -> Result<(), String> {
+    match app.try_state::<WaylandShortcutState>() {
+        Some(state) => state.validate(raw).map(|_| ()),
+        None => parse_binding(raw).map(|_| ()),
+    }
+}
+
+/// Initialize Wayland shortcuts. Returns once the manager task is spawned;
+/// if neither backend starts, the manager switches back to `fallback`.
+pub fn init_shortcuts(app: &AppHandle, fallback: KeyboardImplementation) -> Result<(), String> {
+    #[cfg(not(target_os = "linux"))]
+    {
+        let _ = (app, fallback);
+        Err("Wayland shortcuts are only available on Linux".to_string())
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        if !crate::utils::is_wayland() {
+            return Err("Not running in a Wayland session".to_string());
+        }
+
+        let default_bindings = settings::get_default_settings().bindings;
+        let user_settings = settings::load_or_create_app_settings(app);
+
+        let mut initial = HashMap::new();
+        for (id, default_binding) in default_bindings {
+            if id == CANCEL_BINDING_ID {
+                continue;
+            }
+            // Skip post-processing shortcut when the feature is disabled
+            if id == "transcribe_with_post_process" && !user_settings.post_process_enabled {
+                continue;
+            }
+
+            let binding = user_settings
+                .bindings
+                .get(&id)
+                .cloned()
+                .unwrap_or(default_binding);
+
+            match parse_binding(&binding.current_binding) {
+                Ok(shortcut) => {
+                    initial.insert(
+                        binding.id,
+                        WaylandBinding {
+                            raw: binding.current_binding,
+                            shortcut,
+                        },
+                    );
+                }
+                Err(e) => error!(
+                    "Failed to register Wayland shortcut {} during init: {}",
+                    id, e
+                ),
+            }
+        }
+
+        // A previous manager that failed to start leaves its state behind
+        if let Some(state) = app.try_state::<WaylandShortcutState>() {
+            state.restart(app.clone(), initial, fallback)?;
+        } else {
+            app.manage(WaylandShortcutState::new(app.clone(), initial, fallback));
+        }
+        info!("Wayland shortcut manager started");
+        Ok(())
+    }
+}
+
+/// Register the cancel shortcut (called when recording starts). Only takes
+/// effect with the evdev backend; the portal session never binds it.
+pub fn register_cancel_shortcut(app: &AppHandle) {
+    let Some(state) = app.try_state::<WaylandShortcutState>() else {
+        return;
+    };
+    if state.backend() != Backend::Evdev {
+        return;
+    }
+    let Some(cancel) = crate::settings::get_bindings(app).remove(CANCEL_BINDING_ID) else {
+        return;
+    };
+    let _ = state.register(&cancel);
+}
+
+/// Unregister the cancel shortcut (called when recording stops)
+pub fn unregister_cancel_shortcut(app: &AppHandle) {
+    let Some(state) = app.try_state::<WaylandShortcutState>() else {
+        return;
+    };
+    if state.backend() != Backend::Evdev {
+        return;
+    }
+    if let Some(cancel) = crate::settings::get_bindings(app).remove(CANCEL_BINDING_ID) {
+        let _ = state.unregister(&cancel);
+    }
+}
+
+/// Register a shortcut
+pub fn register_shortcut(app: &AppHandle, binding: ShortcutBinding) -> Result<(), String> {
+    let state = app
+        .try_state::<WaylandShortcutState>()
+        .ok_or("WaylandShortcutState not initialized")?;
+    state.register(&binding)
+}
+
+/// Unregister a shortcut
+pub fn unregister_shortcut(app: &AppHandle, binding: ShortcutBinding) -> Result<(), String> {
+    let state = app
+        .try_state::<WaylandShortcutState>()
+        .ok_or("WaylandShortcutState not initialized")?;
+    state.unregister(&binding)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_modifiers_and_key() {
+        let parsed = parse_shortcut("ctrl+shift+space").expect("valid shortcut");
+        assert_eq!(parsed.modifiers, vec![Modifier::Ctrl, Modifier::Shift]);
+        assert_eq!(parsed.key, "space");
+    }
+
+    #[test]
+    fn rejects_modifier_only_and_fn_shortcuts() {
+        assert!(parse_shortcut("ctrl+shift").is_err());
+        assert!(parse_shortcut("fn+space").is_err());
+        assert!(parse_shortcut("ctrl+a+b").is_err());
+    }
+
+    #[test]
+    fn converts_to_portal_trigger() {
+        let parsed = parse_shortcut("super+alt+f5").expect("valid shortcut");
+        assert_eq!(to_portal_trigger(&parsed), "LOGO+ALT+F5");
+
+        let parsed = parse_shortcut("ctrl+escape").expect("valid shortcut");
+        assert_eq!(to_portal_trigger(&parsed), "CTRL+Escape");
+    }
+}
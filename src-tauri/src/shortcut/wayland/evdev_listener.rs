@@ -0,0 +1,274 @@
+//! evdev fallback backend
+//!
+//! Reads key events straight from keyboard devices in `/dev/input`. Used when
+//! the compositor has no `GlobalShortcuts` portal. Requires read access to the
+//! input devices, which normally means being a member of the `input` group.
+
+use evdev::{Device, InputEventKind, Key};
+use log::{debug, info, warn};
+use std::collections::{HashMap, HashSet};
+use std::sync::mpsc;
+use std::thread;
+use tauri::AppHandle;
+
+use super::{BindingMap, Modifier};
+use crate::shortcut::handler::handle_shortcut_event;
+
+/// evdev key values
+const KEY_RELEASED: i32 = 0;
+const KEY_PRESSED: i32 = 1;
+
+/// A binding resolved to evdev key codes
+struct EvdevBinding {
+    raw: String,
+    key: Key,
+    modifiers: Vec<Modifier>,
+}
+
+enum ListenerEvent {
+    Key(Key, i32),
+    /// Replaces the bindings the dispatcher matches against
+    Rebind(HashMap<String, EvdevBinding>),
+}
+
+/// Handle to the running listener
+pub(super) struct EvdevListener {
+    events: mpsc::Sender<ListenerEvent>,
+}
+
+impl EvdevListener {
+    /// Hand the current bindings to the dispatcher thread.
+    pub(super) fn rebind(&self, bindings: &BindingMap) {
+        let _ = self.events.send(ListenerEvent::Rebind(resolve(bindings)));
+    }
+}
+
+/// Whether a lowercased key name has an evdev key code.
+pub(super) fn supports_key(name: &str) -> bool {
+    evdev_key(name).is_some()
+}
+
+fn resolve(bindings: &BindingMap) -> HashMap<String, EvdevBinding> {
+    let Ok(bindings) = bindings.lock() else {
+        return HashMap::new();
+    };
+    bindings
+        .iter()
+        .filter_map(|(id, binding)| {
+            let Some(key) = evdev_key(&binding.shortcut.key) else {
+                warn!(
+                    "Ignoring shortcut '{}' for {}: no evdev key code for '{}'",
+                    binding.raw, id, binding.shortcut.key
+                );
+                return None;
+            };
+            Some((
+                id.clone(),
+                EvdevBinding {
+                    raw: binding.raw.clone(),
+                    key,
+                    modifiers: binding.shortcut.modifiers.clone(),
+                },
+            ))
+        })
+        .collect()
+}
+
+fn is_keyboard(device: &Device) -> bool {
+    device
+        .supported_keys()
+        .map(|keys| keys.contains(Key::KEY_A) && keys.contains(Key::KEY_SPACE))
+        .unwrap_or(false)
+}
+
+/// Start one reader thread per keyboard plus a dispatcher thread.
+pub(super) fn spawn(app: AppHandle, bindings: &BindingMap) -> Result<EvdevListener, String> {
+    let keyboards: Vec<Device> = evdev::enumerate()
+        .map(|(_, device)| device)
+        .filter(is_keyboard)
+        .collect();
+
+    if keyboards.is_empty() {
+        return Err(
+            "no readable keyboard devices in /dev/input (is the user in the 'input' group?)"
+                .to_string(),
+        );
+    }
+
+    let (event_tx, event_rx) = mpsc::channel::<ListenerEvent>();
+
+    for mut device in keyboards {
+        let event_tx = event_tx.clone();
+        let name = device.name().unwrap_or("unknown").to_string();
+        debug!("Listening for shortcuts on evdev device '{}'", name);
+        thread::spawn(move || loop {
+            let events = match device.fetch_events() {
+                Ok(events) => events,
+                Err(e) => {
+                    warn!("Stopped reading evdev device '{}': {}", name, e);
+                    return;
+                }
+            };
+            for event in events {
+                if let InputEventKind::Key(key) = event.kind() {
+                    if event_tx
+                        .send(ListenerEvent::Key(key, event.value()))
+                        .is_err()
+                    {
+                        return;
+                    }
+                }
+            }
+        });
+    }
+
+    let resolved = resolve(bindings);
+    thread::spawn(move || dispatch_loop(app, resolved, event_rx));
+    info!("evdev shortcut listener started");
+    Ok(EvdevListener { events: event_tx })
+}
+
+fn dispatch_loop(
+    app: AppHandle,
+    mut bindings: HashMap<String, EvdevBinding>,
+    event_rx: mpsc::Receiver<ListenerEvent>,
+) {
+    let mut pressed: HashSet<Key> = HashSet::new();
+    // Binding ids whose shortcut is currently held down
+    let mut active: HashSet<String> = HashSet::new();
+
+    while let Ok(event) = event_rx.recv() {
+        let (key, value) = match event {
+            ListenerEvent::Key(key, value) => (key, value),
+            ListenerEvent::Rebind(resolved) => {
+                bindings = resolved;
+                continue;
+            }
+        };
+
+        match value {
+            KEY_PRESSED => {
+                pressed.insert(key);
+                for (binding_id, binding) in &bindings {
+                    if binding.key == key
+                        && modifiers_held(&binding.modifiers, &pressed)
+                        && active.insert(binding_id.clone())
+                    {
+                        handle_shortcut_event(&app, binding_id, &binding.raw, true);
+                    }
+                }
+            }
+            KEY_RELEASED => {
+                pressed.remove(&key);
+                let released: Vec<String> = active
+                    .iter()
+                    .filter(|binding_id| {
+                        bindings
+                            .get(*binding_id)
+                            .map(|binding| {
+                                binding.key == key || !modifiers_held(&binding.modifiers, &pressed)
+                            })
+                            // Binding was unregistered while held
+                            .unwrap_or(true)
+                    })
+                    .cloned()
+                    .collect();
+
+                for binding_id in released {
+                    active.remove(&binding_id);
+                    if let Some(binding) = bindings.get(&binding_id) {
+                        handle_shortcut_event(&app, &binding_id, &binding.raw, false);
+                    }
+                }
+            }
+            // Auto-repeat
+            _ => {}
+        }
+    }
+}
+
+fn modifiers_held(modifiers: &[Modifier], pressed: &HashSet<Key>) -> bool {
+    modifiers.iter().all(|modifier| {
+        let (left, right) = match modifier {
+            Modifier::Ctrl => (Key::KEY_LEFTCTRL, Key::KEY_RIGHTCTRL),
+            Modifier::Shift => (Key::KEY_LEFTSHIFT, Key::KEY_RIGHTSHIFT),
+            Modifier::Alt => (Key::KEY_LEFTALT, Key::KEY_RIGHTALT),
+            Modifier::Super => (Key::KEY_LEFTMETA, Key::KEY_RIGHTMETA),
+        };
+        pressed.contains(&left) || pressed.contains(&right)
+    })
+}
+
+/// Map a lowercased key name to its evdev key code (US layout).
+fn evdev_key(name: &str) -> Option<Key> {
+    let key = match name {
+        "a" => Key::KEY_A,
+        "b" => Key::KEY_B,
+        "c" => Key::KEY_C,
+        "d" => Key::KEY_D,
+        "e" => Key::KEY_E,
+        "f" => Key::KEY_F,
+        "g" => Key::KEY_G,
+        "h" => Key::KEY_H,
+        "i" => Key::KEY_I,
+        "j" => Key::KEY_J,
+        "k" => Key::KEY_K,
+        "l" => Key::KEY_L,
+        "m" => Key::KEY_M,
+        "n" => Key::KEY_N,
+        "o" => Key::KEY_O,
+        "p" => Key::KEY_P,
+        "q" => Key::KEY_Q,
+        "r" => Key::KEY_R,
+        "s" => Key::KEY_S,
+        "t" => Key::KEY_T,
+        "u" => Key::KEY_U,
+        "v" => Key::KEY_V,
+        "w" => Key::KEY_W,
+        "x" => Key::KEY_X,
+        "y" => Key::KEY_Y,
+        "z" => Key::KEY_Z,
+        "0" => Key::KEY_0,
+        "1" => Key::KEY_1,
+        "2" => Key::KEY_2,
+        "3" => Key::KEY_3,
+        "4" => Key::KEY_4,
+        "5" => Key::KEY_5,
+        "6" => Key::KEY_6,
+        "7" => Key::KEY_7,
+        "8" => Key::KEY_8,
+        "9" => Key::KEY_9,
+        "f1" => Key::KEY_F1,
+        "f2" => Key::KEY_F2,
+        "f3" => Key::KEY_F3,
+        "f4" => Key::KEY_F4,
+        "f5" => Key::KEY_F5,
+        "f6" => Key::KEY_F6,
+        "f7" => Key::KEY_F7,
+        "f8" => Key::KEY_F8,
+        "f9" => Key::KEY_F9,
+        "f10" => Key::KEY_F10,
+        "f11" => Key::KEY_F11,
+        "f12" => Key::KEY_F12,
+        "space" => Key::KEY_SPACE,
+        "escape" | "esc" => Key::KEY_ESC,
+        "enter" | "return" => Key::KEY_ENTER,
+        "tab" => Key::KEY_TAB,
+        "backspace" => Key::KEY_BACKSPACE,
+        "delete" => Key::KEY_DELETE,
+        "insert" => Key::KEY_INSERT,
+        "home" => Key::KEY_HOME,
+        "end" => Key::KEY_END,
+        "pageup" => Key::KEY_PAGEUP,
+        "pagedown" => Key::KEY_PAGEDOWN,
+        "up" | "arrowup" => Key::KEY_UP,
+        "down" | "arrowdown" => Key::KEY_DOWN,
+        "left" | "arrowleft" => Key::KEY_LEFT,
+        "right" | "arrowright" => Key::KEY_RIGHT,
+        "capslock" => Key::KEY_CAPSLOCK,
+        "pause" => Key::KEY_PAUSE,
+        "scrolllock" => Key::KEY_SCROLLLOCK,
+        _ => return None,
+    };
+    Some(key)
+}
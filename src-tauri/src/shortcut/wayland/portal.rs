@@ -0,0 +1,148 @@
+//! XDG `GlobalShortcuts` portal backend
+
+use ashpd::desktop::global_shortcuts::{GlobalShortcuts, NewShortcut};
+use ashpd::desktop::Session;
+use futures_util::StreamExt;
+use log::{debug, error, info};
+use std::collections::HashMap;
+use tauri::AppHandle;
+use tokio::sync::mpsc::UnboundedReceiver;
+
+use super::{
+    set_backend, to_portal_trigger, Backend, BackendStatus, BindingMap, ManagerCommand,
+    WaylandBinding, CANCEL_BINDING_ID, REBIND_DEBOUNCE,
+};
+use crate::shortcut::handler::handle_shortcut_event;
+
+/// Bindings to register with the portal. The cancel shortcut is left out, see
+/// the module docs.
+fn portal_bindings(bindings: &BindingMap) -> HashMap<String, WaylandBinding> {
+    let mut snapshot = bindings.lock().map(|b| b.clone()).unwrap_or_default();
+    snapshot.remove(CANCEL_BINDING_ID);
+    snapshot
+}
+
+/// Create a new portal session and bind every registered shortcut to it.
+async fn bind_all<'a>(
+    proxy: &'a GlobalShortcuts<'a>,
+    bindings: &HashMap<String, WaylandBinding>,
+) -> ashpd::Result<Session<'a, GlobalShortcuts<'a>>> {
+    let session = proxy.create_session().await?;
+
+    let shortcuts: Vec<NewShortcut> = bindings
+        .iter()
+        .map(|(id, binding)| {
+            let trigger = to_portal_trigger(&binding.shortcut);
+            NewShortcut::new(id.as_str(), id.as_str()).preferred_trigger(trigger.as_str())
+        })
+        .collect();
+
+    let response = proxy
+        .bind_shortcuts(&session, &shortcuts, None)
+        .await?
+        .response()?;
+
+    for shortcut in response.shortcuts() {
+        info!(
+            "Portal bound shortcut '{}' to {}",
+            shortcut.id(),
+            shortcut.trigger_description()
+        );
+    }
+
+    Ok(session)
+}
+
+/// Run the portal session until shutdown. Returns an error if the portal
+/// can't be used at all, in which case the caller falls back to evdev.
+/// Once the session is bound, the backend status is set to `Portal`.
+pub(super) async fn run(
+    app: &AppHandle,
+    bindings: &BindingMap,
+    backend: &BackendStatus,
+    cmd_rx: &mut UnboundedReceiver<ManagerCommand>,
+) -> Result<(), String> {
+    let proxy = GlobalShortcuts::new()
+        .await
+        .map_err(|e| format!("failed to connect to portal: {}", e))?;
+
+    let mut bound = portal_bindings(bindings);
+    let mut session = bind_all(&proxy, &bound)
+        .await
+        .map_err(|e| format!("failed to bind shortcuts: {}", e))?;
+
+    let mut activated = proxy
+        .receive_activated()
+        .await
+        .map_err(|e| format!("failed to listen for activations: {}", e))?;
+    let mut deactivated = proxy
+        .receive_deactivated()
+        .await
+        .map_err(|e| format!("failed to listen for deactivations: {}", e))?;
+
+    info!("GlobalShortcuts portal session started");
+    set_backend(backend, Backend::Portal);
+
+    loop {
+        tokio::select! {
+            cmd = cmd_rx.recv() => match cmd {
+                Some(ManagerCommand::Rebind) => {
+                    // Let related changes (e.g. unregister + register when a
+                    // binding changes) land so they cost a single session update
+                    tokio::time::sleep(REBIND_DEBOUNCE).await;
+                    let mut shutdown = false;
+                    while let Ok(cmd) = cmd_rx.try_recv() {
+                        shutdown |= matches!(cmd, ManagerCommand::Shutdown);
+                    }
+                    if shutdown {
+                        let _ = session.close().await;
+                        info!("GlobalShortcuts portal session closed");
+                        return Ok(());
+                    }
+
+                    let snapshot = portal_bindings(bindings);
+                    if snapshot == bound {
+                        continue;
+                    }
+                    // Shortcuts can't be removed from a session, so start a new one
+                    let _ = session.close().await;
+                    match bind_all(&proxy, &snapshot).await {
+                        Ok(new_session) => {
+                            session = new_session;
+                            bound = snapshot;
+                        }
+                        Err(e) => error!("Failed to rebind portal shortcuts: {}", e),
+                    }
+                }
+                Some(ManagerCommand::Shutdown) | None => {
+                    let _ = session.close().await;
+                    info!("GlobalShortcuts portal session closed");
+                    return Ok(());
+                }
+            },
+            Some(event) = activated.next() => {
+                dispatch(app, bindings, event.shortcut_id(), true);
+            }
+            Some(event) = deactivated.next() => {
+                dispatch(app, bindings, event.shortcut_id(), false);
+            }
+        }
+    }
+}
+
+fn dispatch(app: &AppHandle, bindings: &BindingMap, binding_id: &str, is_pressed: bool) {
+    let hotkey_string = match bindings.lock() {
+        Ok(b) => match b.get(binding_id) {
+            Some(binding) => binding.raw.clone(),
+            // Event from a session we've already replaced
+            None => return,
+        },
+        Err(_) => return,
+    };
+
+    debug!(
+        "Portal shortcut event: binding={}, pressed={}",
+        binding_id, is_pressed
+    );
+    handle_shortcut_event(app, binding_id, &hotkey_string, is_pressed);
+}
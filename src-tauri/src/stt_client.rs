@@ -3,22 +3,145 @@
 //! This module provides HTTP client functionality for sending audio
 //! to OpenAI-compatible STT endpoints (like whisper, faster-whisper, parakeet-mlx, etc.)
 
+use crate::metrics::{as_ms, elapsed_ms, LatencyMetrics};
 use crate::settings::{get_settings, SttApiProvider};
+use crate::worker_pool;
+use bytes::Bytes;
+use futures_util::StreamExt;
 use log::{debug, error, info};
 use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Mutex};
+use std::task::Poll;
+use std::time::{Duration, Instant};
+
+/// Chunk size used when streaming audio to the STT API
+const UPLOAD_CHUNK_SIZE: usize = 64 * 1024;
 
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct SttTranscriptionResponse {
     pub text: String,
 }
 
-/// Send audio to an OpenAI-compatible STT API endpoint
+/// Sample rate of the audio handed to the STT API
+const WAV_SAMPLE_RATE: u32 = 16000;
+
+/// 16-bit mono PCM
+const BYTES_PER_SAMPLE: usize = 2;
+
+/// Size of the canonical PCM WAV header
+const WAV_HEADER_LEN: usize = 44;
+
+/// Total size of the WAV file for `sample_count` samples
+fn wav_len(sample_count: usize) -> u64 {
+    (WAV_HEADER_LEN + sample_count * BYTES_PER_SAMPLE) as u64
+}
+
+/// Header of a 16kHz mono 16-bit PCM WAV file holding `sample_count` samples
+fn wav_header(sample_count: usize) -> Vec<u8> {
+    let channels: u16 = 1;
+    let bits_per_sample: u16 = 16;
+    let data_chunk_size = (sample_count * BYTES_PER_SAMPLE) as u32;
+    let byte_rate = WAV_SAMPLE_RATE * channels as u32 * BYTES_PER_SAMPLE as u32;
+    let block_align = channels * BYTES_PER_SAMPLE as u16;
+
+    let mut header = Vec::with_capacity(WAV_HEADER_LEN);
+
+    // RIFF header
+    header.extend_from_slice(b"RIFF");
+    header.extend_from_slice(&(36 + data_chunk_size).to_le_bytes()); // File size - 8
+    header.extend_from_slice(b"WAVE");
+
+    // fmt chunk
+    header.extend_from_slice(b"fmt ");
+    header.extend_from_slice(&16u32.to_le_bytes()); // Subchunk1Size (16 for PCM)
+    header.extend_from_slice(&1u16.to_le_bytes()); // AudioFormat (1 = PCM)
+    header.extend_from_slice(&channels.to_le_bytes());
+    header.extend_from_slice(&WAV_SAMPLE_RATE.to_le_bytes());
+    header.extend_from_slice(&byte_rate.to_le_bytes());
+    header.extend_from_slice(&block_align.to_le_bytes());
+    header.extend_from_slice(&bits_per_sample.to_le_bytes());
+
+    // data chunk
+    header.extend_from_slice(b"data");
+    header.extend_from_slice(&data_chunk_size.to_le_bytes());
+
+    header
+}
+
+/// Convert f32 samples (-1.0 to 1.0) to little-endian 16-bit PCM bytes
+fn encode_pcm16(samples: &[f32]) -> Vec<u8> {
+    let mut pcm = Vec::with_capacity(samples.len() * BYTES_PER_SAMPLE);
+    for &sample in samples {
+        pcm.extend_from_slice(&((sample.clamp(-1.0, 1.0) * 32767.0) as i16).to_le_bytes());
+    }
+    pcm
+}
+
+/// Produce the WAV file for `samples` in upload-sized chunks, each encoded only
+/// when it is requested, so the whole file never sits in memory next to the
+/// samples. Time spent encoding is added to `encode_time`.
+fn wav_chunks(
+    samples: Arc<[f32]>,
+    encode_time: Arc<Mutex<Duration>>,
+) -> impl Iterator<Item = Bytes> + Send + 'static {
+    let header = Bytes::from(wav_header(samples.len()));
+    let samples_per_chunk = UPLOAD_CHUNK_SIZE / BYTES_PER_SAMPLE;
+    let chunk_count = samples.len().div_ceil(samples_per_chunk);
+
+    std::iter::once(header).chain((0..chunk_count).map(move |i| {
+        let encode_start = Instant::now();
+        let start = i * samples_per_chunk;
+        let end = (start + samples_per_chunk).min(samples.len());
+        let chunk = Bytes::from(encode_pcm16(&samples[start..end]));
+        if let Ok(mut encode_time) = encode_time.lock() {
+            *encode_time += encode_start.elapsed();
+        }
+        chunk
+    }))
+}
+
+/// Wrap the WAV chunks in a streaming body that records when the last chunk
+/// has been handed to the connection, which marks the end of the upload.
+/// Chunks are encoded on the worker pool, one at a time as the connection
+/// asks for them.
+fn upload_tracking_body(
+    chunks: impl Iterator<Item = Bytes> + Send + 'static,
+    uploaded_at: Arc<Mutex<Option<Instant>>>,
+) -> reqwest::Body {
+    let encoded = futures_util::stream::unfold(Some(chunks), |chunks| async move {
+        let mut chunks = chunks?;
+        let result = worker_pool::run(move || {
+            let chunk = chunks.next();
+            (chunks, chunk)
+        })
+        .await;
+
+        match result {
+            Ok((chunks, Some(chunk))) => Some((Ok(chunk), Some(chunks))),
+            Ok((_, None)) => None,
+            Err(e) => Some((Err(std::io::Error::other(e)), None)),
+        }
+    });
+
+    let mark_done = futures_util::stream::poll_fn(move |_| {
+        if let Ok(mut uploaded_at) = uploaded_at.lock() {
+            uploaded_at.get_or_insert_with(Instant::now);
+        }
+        Poll::Ready(None)
+    });
+
+    reqwest::Body::wrap_stream(encoded.chain(mark_done))
+}
+
+/// Send audio to an OpenAI-compatible STT API endpoint.
+/// Encode, upload and response times are recorded into `metrics`.
 pub async fn transcribe_audio(
     provider: &SttApiProvider,
     api_key: String,
     model: &str,
-    audio_samples: Vec<f32>,
+    audio_samples: Arc<[f32]>,
     language: Option<String>,
+    metrics: &mut LatencyMetrics,
 ) -> Result<String, String> {
     let base_url = provider.base_url.trim_end_matches('/');
     let url = format!("{}/audio/transcriptions", base_url);
@@ -28,15 +151,21 @@ pub async fn transcribe_audio(
         url, model, language
     );
 
-    // Convert f32 samples to 16-bit PCM bytes for WAV
-    let wav_bytes = samples_to_wav(audio_samples);
+    // The WAV file is encoded chunk by chunk while it is being uploaded
+    let wav_len = wav_len(audio_samples.len());
+    let encode_time: Arc<Mutex<Duration>> = Arc::default();
+    let uploaded_at: Arc<Mutex<Option<Instant>>> = Arc::default();
+    let wav_body = upload_tracking_body(
+        wav_chunks(audio_samples, Arc::clone(&encode_time)),
+        Arc::clone(&uploaded_at),
+    );
 
     // Build the multipart form
     let client = reqwest::Client::new();
     let mut form = reqwest::multipart::Form::new()
         .part(
             "file",
-            reqwest::multipart::Part::bytes(wav_bytes)
+            reqwest::multipart::Part::stream_with_length(wav_body, wav_len)
                 .file_name("audio.wav")
                 .mime_str("audio/wav")
                 .map_err(|e| format!("Failed to create file part: {}", e))?,
@@ -64,6 +193,7 @@ pub async fn transcribe_audio(
     debug!("Sending STT request to {}", url);
 
     // Send request
+    let request_start = Instant::now();
     let response = request
         .send()
         .await
@@ -75,6 +205,23 @@ pub async fn transcribe_audio(
         .await
         .map_err(|e| format!("Failed to read response body: {}", e))?;
 
+    let uploaded_at = uploaded_at
+        .lock()
+        .ok()
+        .and_then(|uploaded_at| *uploaded_at)
+        .unwrap_or(request_start);
+    let encode_time = encode_time
+        .lock()
+        .map(|encode_time| *encode_time)
+        .unwrap_or_default();
+    metrics.encode_ms = Some(as_ms(encode_time));
+    metrics.upload_ms = Some(as_ms(
+        uploaded_at
+            .saturating_duration_since(request_start)
+            .saturating_sub(encode_time),
+    ));
+    metrics.inference_ms = elapsed_ms(uploaded_at);
+
     if !status.is_success() {
         error!("STT API error ({}): {}", status, body);
         return Err(format!("STT API error ({}): {}", status, body));
@@ -96,59 +243,11 @@ pub async fn transcribe_audio(
     Ok(text)
 }
 
-/// Convert f32 audio samples (16kHz, mono) to WAV format bytes
-fn samples_to_wav(samples: Vec<f32>) -> Vec<u8> {
-    use std::io::Write;
-
-    let sample_rate: u32 = 16000;
-    let channels: u16 = 1;
-    let bits_per_sample: u16 = 16;
-    let bytes_per_sample = bits_per_sample / 8;
-
-    // Convert f32 (-1.0 to 1.0) to i16
-    let pcm_data: Vec<i16> = samples
-        .iter()
-        .map(|&s| (s.clamp(-1.0, 1.0) * 32767.0) as i16)
-        .collect();
-
-    let data_chunk_size = pcm_data.len() as u32 * bytes_per_sample as u32;
-    let byte_rate = sample_rate * channels as u32 * bytes_per_sample as u32;
-    let block_align = channels * bytes_per_sample;
-
-    let mut wav = Vec::new();
-
-    // RIFF header
-    wav.write_all(b"RIFF").unwrap();
-    wav.write_all(&(36 + data_chunk_size).to_le_bytes())
-        .unwrap(); // File size - 8
-    wav.write_all(b"WAVE").unwrap();
-
-    // fmt chunk
-    wav.write_all(b"fmt ").unwrap();
-    wav.write_all(&16u32.to_le_bytes()).unwrap(); // Subchunk1Size (16 for PCM)
-    wav.write_all(&1u16.to_le_bytes()).unwrap(); // AudioFormat (1 = PCM)
-    wav.write_all(&channels.to_le_bytes()).unwrap();
-    wav.write_all(&sample_rate.to_le_bytes()).unwrap();
-    wav.write_all(&byte_rate.to_le_bytes()).unwrap();
-    wav.write_all(&block_align.to_le_bytes()).unwrap();
-    wav.write_all(&bits_per_sample.to_le_bytes()).unwrap();
-
-    // data chunk
-    wav.write_all(b"data").unwrap();
-    wav.write_all(&data_chunk_size.to_le_bytes()).unwrap();
-
-    // Write PCM data
-    for sample in pcm_data {
-        wav.write_all(&sample.to_le_bytes()).unwrap();
-    }
-
-    wav
-}
-
 /// Transcribe audio using the configured STT API provider
 pub async fn transcribe_with_stt_api(
     app_handle: &tauri::AppHandle,
-    audio_samples: Vec<f32>,
+    audio_samples: Arc<[f32]>,
+    metrics: &mut LatencyMetrics,
 ) -> Result<String, String> {
     let settings = get_settings(app_handle);
 
@@ -186,5 +285,36 @@ pub async fn transcribe_with_stt_api(
         Some(settings.selected_language.clone())
     };
 
-    transcribe_audio(&provider, api_key, &model, audio_samples, language).await
+    transcribe_audio(&provider, api_key, &model, audio_samples, language, metrics).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn streamed_wav_matches_samples() {
+        // Not a multiple of the chunk size, so the last chunk is partial
+        let samples: Vec<f32> = (0..40_000)
+            .map(|i| ((i % 200) as f32 / 100.0) - 1.0)
+            .collect();
+        let expected: Vec<i16> = samples
+            .iter()
+            .map(|&s| (s.clamp(-1.0, 1.0) * 32767.0) as i16)
+            .collect();
+
+        let wav: Vec<u8> = wav_chunks(Arc::from(samples.as_slice()), Arc::default())
+            .flat_map(|chunk| chunk.to_vec())
+            .collect();
+        assert_eq!(wav.len() as u64, wav_len(samples.len()));
+
+        let mut reader = hound::WavReader::new(std::io::Cursor::new(wav)).unwrap();
+        let spec = reader.spec();
+        assert_eq!(spec.sample_rate, WAV_SAMPLE_RATE);
+        assert_eq!(spec.channels, 1);
+        assert_eq!(spec.bits_per_sample, 16);
+
+        let decoded: Vec<i16> = reader.samples::<i16>().map(Result::unwrap).collect();
+        assert_eq!(decoded, expected);
+    }
 }
@@ -107,13 +107,23 @@ pub fn update_tray_menu(app: &AppHandle, state: &TrayIconState, locale: Option<&
         settings_accelerator,
     )
     .expect("failed to create settings item");
-    let check_updates_i = MenuItem::with_id(
-        app,
-        "check_updates",
-        &strings.check_updates,
-        settings.update_checks_enabled,
-        None::<&str>,
-    )
+    // Offer to install a found update in place of the manual check
+    let check_updates_i = match crate::updater::pending_version(app) {
+        Some(version) => MenuItem::with_id(
+            app,
+            "install_update",
+            strings.install_update.replace("{{version}}", &version),
+            true,
+            None::<&str>,
+        ),
+        None => MenuItem::with_id(
+            app,
+            "check_updates",
+            &strings.check_updates,
+            settings.update_checks_enabled,
+            None::<&str>,
+        ),
+    }
     .expect("failed to create check updates item");
     let copy_last_transcript_i = MenuItem::with_id(
         app,
@@ -213,6 +223,7 @@ mod tests {
             transcription_text: transcription.to_string(),
             post_processed_text: post_processed.map(|text| text.to_string()),
             post_process_prompt: None,
+            latency_metrics: None,
         }
     }
 
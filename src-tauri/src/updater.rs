@@ -0,0 +1,381 @@
+//! Update checks with release channels
+//!
+//! The stable channel uses the updater endpoint from tauri.conf.json, which
+//! serves the manifest of the latest GitHub release. The beta channel asks the
+//! GitHub API for the newest release including pre-releases and uses the
+//! `latest.json` manifest attached to it. Checks run in the background on a
+//! schedule while update checks are enabled, and right away when the update
+//! settings change. A found update is announced with the `update-available`
+//! event and an install item in the tray menu; updates found in the background
+//! also get a system notification.
+
+use crate::pipeline;
+use crate::settings::{get_settings, UpdateChannel};
+use crate::tray::{self, TrayIconState};
+use crate::tray_i18n::get_tray_translations;
+use log::{debug, info, warn};
+use serde::{Deserialize, Serialize};
+use specta::Type;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager, Url};
+use tauri_plugin_notification::NotificationExt;
+use tauri_plugin_opener::OpenerExt;
+use tauri_plugin_updater::{Update, UpdaterExt};
+use tokio::sync::Notify;
+
+const GITHUB_REPO: &str = "cjpais/Handy";
+
+/// Name of the updater manifest attached to every release
+const MANIFEST_ASSET: &str = "latest.json";
+
+/// Delay before the first background check, so it doesn't compete with startup
+const INITIAL_CHECK_DELAY: Duration = Duration::from_secs(30);
+
+const CHECK_INTERVAL: Duration = Duration::from_secs(6 * 60 * 60);
+
+#[derive(Serialize, Debug, Clone, Type)]
+pub struct UpdateInfo {
+    pub version: String,
+    pub current_version: String,
+    /// Release notes
+    pub notes: Option<String>,
+    pub date: Option<String>,
+    pub channel: UpdateChannel,
+    /// The update can be downloaded and applied in place. When false it has to
+    /// be installed from the release page (e.g. .deb/.rpm installs on Linux).
+    pub can_install: bool,
+    pub release_url: String,
+}
+
+#[derive(Serialize, Debug, Clone, Type)]
+pub struct UpdateDownloadProgress {
+    pub downloaded: u64,
+    pub total: Option<u64>,
+}
+
+/// Update found by the most recent check
+#[derive(Default)]
+pub struct UpdateState {
+    pending: Mutex<Option<(Update, UpdateInfo)>>,
+    installing: AtomicBool,
+    /// Wakes the background checks when the update settings change
+    settings_changed: Notify,
+}
+
+#[derive(Deserialize)]
+struct GithubAsset {
+    name: String,
+    browser_download_url: String,
+}
+
+#[derive(Deserialize)]
+struct GithubRelease {
+    draft: bool,
+    assets: Vec<GithubAsset>,
+}
+
+/// Manifest URL of the newest published release. The API lists releases
+/// newest first, pre-releases included.
+fn newest_manifest_url(releases: &[GithubRelease]) -> Option<&str> {
+    releases
+        .iter()
+        .filter(|release| !release.draft)
+        .find_map(|release| {
+            release
+                .assets
+                .iter()
+                .find(|asset| asset.name == MANIFEST_ASSET)
+                .map(|asset| asset.browser_download_url.as_str())
+        })
+}
+
+async fn beta_endpoint(app: &AppHandle) -> Result<Url, String> {
+    let url = format!(
+        "https://api.github.com/repos/{}/releases?per_page=20",
+        GITHUB_REPO
+    );
+    let releases: Vec<GithubRelease> = reqwest::Client::new()
+        .get(&url)
+        // The GitHub API rejects requests without a User-Agent
+        .header(
+            "User-Agent",
+            format!("Handy/{}", app.package_info().version),
+        )
+        .header("Accept", "application/vnd.github+json")
+        .send()
+        .await
+        .and_then(|response| response.error_for_status())
+        .map_err(|e| format!("Failed to list releases: {}", e))?
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse release list: {}", e))?;
+
+    let manifest = newest_manifest_url(&releases)
+        .ok_or_else(|| "No release with an update manifest found".to_string())?;
+    Url::parse(manifest).map_err(|e| format!("Invalid manifest URL: {}", e))
+}
+
+/// Whether this installation can replace itself. On Linux only AppImages
+/// can; .deb and .rpm installs are managed by the package manager.
+fn can_install_in_place() -> bool {
+    #[cfg(target_os = "linux")]
+    {
+        std::env::var_os("APPIMAGE").is_some()
+    }
+
+    #[cfg(not(target_os = "linux"))]
+    {
+        true
+    }
+}
+
+/// Rebuild the tray menu so it shows or drops the install item. While a
+/// transcription is running the menu is left alone; it is rebuilt once the
+/// pipeline returns to idle anyway.
+fn refresh_tray_menu(app: &AppHandle) {
+    if !pipeline::is_idle() {
+        return;
+    }
+    // The tray doesn't exist yet early during startup
+    if app.try_state::<tauri::tray::TrayIcon>().is_some() {
+        tray::update_tray_menu(app, &TrayIconState::Idle, None);
+    }
+}
+
+/// Version of the update found by the last check, if any.
+pub fn pending_version(app: &AppHandle) -> Option<String> {
+    app.try_state::<UpdateState>()?
+        .pending
+        .lock()
+        .ok()?
+        .as_ref()
+        .map(|(_, info)| info.version.clone())
+}
+
+pub fn clear_pending_update(app: &AppHandle) {
+    if let Some(state) = app.try_state::<UpdateState>() {
+        if let Ok(mut pending) = state.pending.lock() {
+            *pending = None;
+        }
+    }
+    refresh_tray_menu(app);
+}
+
+/// Check the configured channel for an update and remember it for
+/// `install_update`. Returns `None` when up to date or checks are disabled.
+pub async fn check_for_updates(app: &AppHandle) -> Result<Option<UpdateInfo>, String> {
+    let settings = get_settings(app);
+    if !settings.update_checks_enabled {
+        return Ok(None);
+    }
+
+    let channel = settings.update_channel;
+    let mut builder = app.updater_builder();
+    if channel == UpdateChannel::Beta {
+        builder = builder
+            .endpoints(vec![beta_endpoint(app).await?])
+            .map_err(|e| format!("Invalid updater endpoint: {}", e))?;
+    }
+    let update = builder
+        .build()
+        .map_err(|e| format!("Failed to create updater: {}", e))?
+        .check()
+        .await
+        .map_err(|e| format!("Update check failed: {}", e))?;
+
+    let info = update.as_ref().map(|update| UpdateInfo {
+        version: update.version.clone(),
+        current_version: update.current_version.clone(),
+        notes: update.body.clone(),
+        date: update.date.map(|date| date.to_string()),
+        channel,
+        can_install: can_install_in_place(),
+        release_url: format!(
+            "https://github.com/{}/releases/tag/v{}",
+            GITHUB_REPO, update.version
+        ),
+    });
+
+    let state = app.state::<UpdateState>();
+    let newly_found = {
+        let mut pending = state
+            .pending
+            .lock()
+            .map_err(|_| "Update state is poisoned".to_string())?;
+        let previous = pending.as_ref().map(|(_, info)| info.version.clone());
+        *pending = update.zip(info.clone());
+        info.as_ref()
+            .is_some_and(|info| previous.as_deref() != Some(info.version.as_str()))
+    };
+
+    match &info {
+        Some(info) if newly_found => {
+            info!(
+                "Update available: {} -> {} ({:?} channel)",
+                info.current_version, info.version, channel
+            );
+            let _ = app.emit("update-available", info);
+        }
+        Some(_) => {}
+        None => debug!("No update available ({:?} channel)", channel),
+    }
+    refresh_tray_menu(app);
+
+    Ok(info)
+}
+
+/// Download and apply the pending update, then restart. Where the platform
+/// can't update in place, open the release page instead.
+pub async fn install_pending_update(app: &AppHandle) -> Result<(), String> {
+    let state = app.state::<UpdateState>();
+    let (update, info) = state
+        .pending
+        .lock()
+        .map_err(|_| "Update state is poisoned".to_string())?
+        .clone()
+        .ok_or_else(|| "No update available".to_string())?;
+
+    if !info.can_install {
+        return app
+            .opener()
+            .open_url(&info.release_url, None::<String>)
+            .map_err(|e| format!("Failed to open release page: {}", e));
+    }
+
+    if state.installing.swap(true, Ordering::SeqCst) {
+        return Err("An update is already being installed".to_string());
+    }
+
+    info!("Installing update {}", info.version);
+    let mut downloaded: u64 = 0;
+    let result = update
+        .download_and_install(
+            |chunk, total| {
+                downloaded += chunk as u64;
+                let _ = app.emit(
+                    "update-download-progress",
+                    UpdateDownloadProgress { downloaded, total },
+                );
+            },
+            || {
+                let _ = app.emit("update-installing", ());
+            },
+        )
+        .await;
+    state.installing.store(false, Ordering::SeqCst);
+
+    result.map_err(|e| format!("Failed to install update: {}", e))?;
+    app.restart()
+}
+
+/// Show a system notification for an update found in the background, as the
+/// main window is usually hidden.
+fn notify_update_available(app: &AppHandle, info: &UpdateInfo) {
+    let strings = get_tray_translations(Some(get_settings(app).app_language));
+    if let Err(e) = app
+        .notification()
+        .builder()
+        .title(strings.update_available_title)
+        .body(
+            strings
+                .update_available_body
+                .replace("{{version}}", &info.version),
+        )
+        .show()
+    {
+        warn!("Failed to show update notification: {}", e);
+    }
+}
+
+/// Check for updates shortly after startup, then periodically and whenever the
+/// update settings change.
+pub fn start_background_checks(app: &AppHandle) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let state = app.state::<UpdateState>();
+        let mut delay = INITIAL_CHECK_DELAY;
+        loop {
+            tokio::select! {
+                _ = tokio::time::sleep(delay) => {}
+                _ = state.settings_changed.notified() => {}
+            }
+            delay = CHECK_INTERVAL;
+
+            if !get_settings(&app).update_checks_enabled {
+                continue;
+            }
+            let previous = pending_version(&app);
+            match check_for_updates(&app).await {
+                Ok(Some(info)) if previous.as_deref() != Some(info.version.as_str()) => {
+                    notify_update_available(&app, &info);
+                }
+                Ok(_) => {}
+                Err(e) => warn!("Background update check failed: {}", e),
+            }
+        }
+    });
+}
+
+/// Run a background check now, e.g. after the update settings changed.
+pub fn wake_background_checks(app: &AppHandle) {
+    if let Some(state) = app.try_state::<UpdateState>() {
+        state.settings_changed.notify_one();
+    }
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn check_for_update(app: AppHandle) -> Result<Option<UpdateInfo>, String> {
+    check_for_updates(&app).await
+}
+
+#[tauri::command]
+#[specta::specta]
+pub fn get_pending_update(app: AppHandle) -> Option<UpdateInfo> {
+    app.state::<UpdateState>()
+        .pending
+        .lock()
+        .ok()?
+        .as_ref()
+        .map(|(_, info)| info.clone())
+}
+
+#[tauri::command]
+#[specta::specta]
+pub async fn install_update(app: AppHandle) -> Result<(), String> {
+    install_pending_update(&app).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn release(draft: bool, assets: &[&str]) -> GithubRelease {
+        GithubRelease {
+            draft,
+            assets: assets
+                .iter()
+                .map(|name| GithubAsset {
+                    name: name.to_string(),
+                    browser_download_url: format!("https://example.com/{}", name),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn picks_newest_published_release_with_manifest() {
+        let releases = vec![
+            release(true, &[MANIFEST_ASSET]),
+            release(false, &["Handy.dmg"]),
+            release(false, &["Handy.AppImage", MANIFEST_ASSET]),
+        ];
+        assert_eq!(
+            newest_manifest_url(&releases),
+            Some("https://example.com/latest.json")
+        );
+        assert_eq!(newest_manifest_url(&releases[..2]), None);
+    }
+}
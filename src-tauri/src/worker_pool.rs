@@ -0,0 +1,162 @@
+//! Bounded worker pool for CPU-heavy processing
+//!
+//! Encoding audio, writing recordings to disk and converting text get slow for
+//! long recordings. Run on the async runtime they hold up every other task,
+//! including the command handlers the UI is waiting on, so they run on a few
+//! dedicated threads instead. The number of queued jobs is capped and callers
+//! wait asynchronously for a free slot rather than piling up work.
+//!
+//! Resampling and voice activity detection already run on the recorder's own
+//! consumer thread, off the audio callback, and local inference runs on a
+//! blocking task since it holds the engine for seconds at a time.
+//!
+//! Jobs are not isolated from panics: release builds use `panic = "abort"`, so
+//! a panicking job ends the process like a panic anywhere else and is picked up
+//! by crash reporting.
+
+use once_cell::sync::Lazy;
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use tokio::sync::{oneshot, Semaphore};
+
+/// Jobs that may be queued or running at once
+const QUEUE_LIMIT: usize = 32;
+
+/// Upper bound on worker threads; the work is bursty and mostly sequential
+const MAX_THREADS: usize = 4;
+
+type Job = Box<dyn FnOnce() + Send + 'static>;
+
+pub struct WorkerPool {
+    sender: mpsc::Sender<Job>,
+    slots: Arc<Semaphore>,
+}
+
+impl WorkerPool {
+    pub fn new(threads: usize, queue_limit: usize) -> Self {
+        let (sender, receiver) = mpsc::channel::<Job>();
+        let receiver = Arc::new(Mutex::new(receiver));
+
+        for i in 0..threads.max(1) {
+            let receiver = Arc::clone(&receiver);
+            let spawned = std::thread::Builder::new()
+                .name(format!("handy-worker-{}", i))
+                .spawn(move || loop {
+                    // Release the lock before running the job so other workers can pick up work
+                    let job = match receiver.lock() {
+                        Ok(receiver) => receiver.recv(),
+                        Err(_) => break,
+                    };
+                    match job {
+                        Ok(job) => job(),
+                        Err(_) => break,
+                    }
+                });
+            if let Err(e) = spawned {
+                log::error!("Failed to spawn worker thread: {}", e);
+            }
+        }
+
+        Self {
+            sender,
+            slots: Arc::new(Semaphore::new(queue_limit.max(1))),
+        }
+    }
+
+    /// Run `job` on the pool and wait for its result without blocking the
+    /// calling task.
+    pub async fn run<F, R>(&self, job: F) -> Result<R, String>
+    where
+        F: FnOnce() -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let slot = Arc::clone(&self.slots)
+            .acquire_owned()
+            .await
+            .map_err(|_| "Worker pool is shut down".to_string())?;
+
+        let (result_tx, result_rx) = oneshot::channel();
+        let job: Job = Box::new(move || {
+            let result = job();
+            drop(slot);
+            let _ = result_tx.send(result);
+        });
+        self.sender
+            .send(job)
+            .map_err(|_| "Worker pool is shut down".to_string())?;
+
+        result_rx
+            .await
+            .map_err(|_| "Worker job was dropped".to_string())
+    }
+}
+
+fn default_thread_count() -> usize {
+    // Leave a core for audio capture and the UI
+    std::thread::available_parallelism()
+        .map(|n| n.get().saturating_sub(1))
+        .unwrap_or(1)
+        .clamp(1, MAX_THREADS)
+}
+
+static POOL: Lazy<WorkerPool> = Lazy::new(|| WorkerPool::new(default_thread_count(), QUEUE_LIMIT));
+
+/// Run `job` on the shared processing pool.
+pub async fn run<F, R>(job: F) -> Result<R, String>
+where
+    F: FnOnce() -> R + Send + 'static,
+    R: Send + 'static,
+{
+    POOL.run(job).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn runs_jobs_off_the_calling_thread() {
+        let pool = WorkerPool::new(2, 4);
+        let caller = std::thread::current().id();
+
+        let (worker, sum) = tauri::async_runtime::block_on(
+            pool.run(move || (std::thread::current().id(), (1..=10).sum::<u32>())),
+        )
+        .unwrap();
+
+        assert_ne!(worker, caller);
+        assert_eq!(sum, 55);
+    }
+
+    #[test]
+    fn limits_jobs_in_flight() {
+        let pool = Arc::new(WorkerPool::new(4, 2));
+        let running = Arc::new(AtomicUsize::new(0));
+        let peak = Arc::new(AtomicUsize::new(0));
+
+        tauri::async_runtime::block_on(async {
+            let jobs: Vec<_> = (0..8)
+                .map(|_| {
+                    let pool = Arc::clone(&pool);
+                    let running = Arc::clone(&running);
+                    let peak = Arc::clone(&peak);
+                    tauri::async_runtime::spawn(async move {
+                        pool.run(move || {
+                            let now = running.fetch_add(1, Ordering::SeqCst) + 1;
+                            peak.fetch_max(now, Ordering::SeqCst);
+                            std::thread::sleep(std::time::Duration::from_millis(20));
+                            running.fetch_sub(1, Ordering::SeqCst);
+                        })
+                        .await
+                    })
+                })
+                .collect();
+            for job in jobs {
+                job.await.unwrap().unwrap();
+            }
+        });
+
+        assert!(peak.load(Ordering::SeqCst) <= 2);
+    }
+}
@@ -1,11 +1,8 @@
 import { useEffect, useState, useRef } from "react";
-import { Toaster } from "sonner";
+import { Toaster, toast } from "sonner";
 import { useTranslation } from "react-i18next";
 import { platform } from "@tauri-apps/plugin-os";
-import {
-  checkAccessibilityPermission,
-  checkMicrophonePermission,
-} from "tauri-plugin-macos-permissions-api";
+import { listen } from "@tauri-apps/api/event";
 import "./App.css";
 import AccessibilityPermissions from "./components/AccessibilityPermissions";
 import Footer from "./components/footer";
@@ -14,6 +11,7 @@ import { Sidebar, SidebarSection, SECTIONS_CONFIG } from "./components/Sidebar";
 import { useSettings } from "./hooks/useSettings";
 import { useSettingsStore } from "./stores/settingsStore";
 import { commands } from "@/bindings";
+import { isPermissionSatisfied } from "@/lib/utils/permissions";
 import { getLanguageDirection, initializeRTL } from "@/lib/utils/rtl";
 
 type OnboardingStep = "accessibility" | "model" | "done";
@@ -25,7 +23,7 @@ const renderSettingsContent = (section: SidebarSection) => {
 };
 
 function App() {
-  const { i18n } = useTranslation();
+  const { t, i18n } = useTranslation();
   const [onboardingStep, setOnboardingStep] = useState<OnboardingStep | null>(
     null,
   );
@@ -34,7 +32,7 @@ function App() {
   const [isReturningUser, setIsReturningUser] = useState(false);
   const [currentSection, setCurrentSection] =
     useState<SidebarSection>("general");
-  const { settings, updateSetting } = useSettings();
+  const { settings, updateSetting, refreshSettings } = useSettings();
   const direction = getLanguageDirection(i18n.language);
   const refreshAudioDevices = useSettingsStore(
     (state) => state.refreshAudioDevices,
@@ -68,6 +66,45 @@ function App() {
     }
   }, [onboardingStep, refreshAudioDevices, refreshOutputDevices]);
 
+  // Warn when a transcription couldn't be pasted and was left on the clipboard
+  useEffect(() => {
+    const unlisten = listen<string>("paste-blocked", (event) => {
+      if (event.payload === "secure_input") {
+        toast.warning(t("pasteBlocked.secureInput"));
+        return;
+      }
+      // Running elevated lets Handy paste into Administrator windows
+      toast.warning(t("pasteBlocked.elevatedWindow"), {
+        action: {
+          label: t("pasteBlocked.restartAsAdministrator"),
+          onClick: async () => {
+            const result = await commands.restartAsAdministrator();
+            if (result.status === "error") {
+              toast.error(result.error);
+            }
+          },
+        },
+      });
+    });
+
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, [t]);
+
+  // The Wayland shortcut backend starts in the background and reverts to the
+  // previous implementation if it can't start
+  useEffect(() => {
+    const unlisten = listen<string>("shortcut-backend-failed", (event) => {
+      toast.error(t("shortcutBackendFailed", { error: event.payload }));
+      refreshSettings();
+    });
+
+    return () => {
+      unlisten.then((fn) => fn());
+    };
+  }, [t, refreshSettings]);
+
   // Handle keyboard shortcuts for debug mode toggle
   useEffect(() => {
     const handleKeyDown = (event: KeyboardEvent) => {
@@ -104,11 +141,12 @@ function App() {
         setIsReturningUser(true);
         if (platform() === "macos") {
           try {
-            const [hasAccessibility, hasMicrophone] = await Promise.all([
-              checkAccessibilityPermission(),
-              checkMicrophonePermission(),
-            ]);
-            if (!hasAccessibility || !hasMicrophone) {
+            const permissions = await commands.getPermissionsStatus();
+            if (
+              permissions.status === "ok" &&
+              (!isPermissionSatisfied(permissions.data.accessibility) ||
+                !isPermissionSatisfied(permissions.data.microphone))
+            ) {
               // Missing permissions - show accessibility onboarding
               setOnboardingStep("accessibility");
               return;
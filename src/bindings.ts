@@ -69,6 +69,14 @@ async changeAutostartSetting(enabled: boolean) : Promise<Result<null, string>> {
     else return { status: "error", error: e  as any };
 }
 },
+async changeAutostartStartHiddenSetting(enabled: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_autostart_start_hidden_setting", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async changeTranslateToEnglishSetting(enabled: boolean) : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_translate_to_english_setting", { enabled }) };
@@ -125,6 +133,14 @@ async changeClipboardHandlingSetting(handling: string) : Promise<Result<null, st
     else return { status: "error", error: e  as any };
 }
 },
+async changeTypingToolSetting(tool: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_typing_tool_setting", { tool }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async changePostProcessEnabledSetting(enabled: boolean) : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("change_post_process_enabled_setting", { enabled }) };
@@ -276,6 +292,22 @@ async changeUpdateChecksSetting(enabled: boolean) : Promise<Result<null, string>
     else return { status: "error", error: e  as any };
 }
 },
+async changeUpdateChannelSetting(channel: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_update_channel_setting", { channel }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async changeCrashReportingSetting(enabled: boolean) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("change_crash_reporting_setting", { enabled }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 /**
  * Change the keyboard implementation with runtime switching.
  * This will unregister all shortcuts from the old implementation,
@@ -326,6 +358,25 @@ async triggerUpdateCheck() : Promise<Result<null, string>> {
     else return { status: "error", error: e  as any };
 }
 },
+async checkForUpdate() : Promise<Result<UpdateInfo | null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("check_for_update") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async getPendingUpdate() : Promise<UpdateInfo | null> {
+    return await TAURI_INVOKE("get_pending_update");
+},
+async installUpdate() : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("install_update") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async cancelOperation() : Promise<void> {
     await TAURI_INVOKE("cancel_operation");
 },
@@ -369,6 +420,20 @@ async setLogLevel(level: LogLevel) : Promise<Result<null, string>> {
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Override the console log level for the current session, or pass `None` to
+ * go back to the level from `RUST_LOG`. Not persisted.
+ */
+async setConsoleLogLevel(level: LogLevel | null) : Promise<void> {
+    await TAURI_INVOKE("set_console_log_level", { level });
+},
+/**
+ * Console log level set with `set_console_log_level`, or `None` while the
+ * console follows `RUST_LOG`.
+ */
+async getConsoleLogLevel() : Promise<LogLevel | null> {
+    return await TAURI_INVOKE("get_console_log_level");
+},
 async openRecordingsFolder() : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("open_recordings_folder") };
@@ -385,6 +450,42 @@ async openLogDir() : Promise<Result<null, string>> {
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Read the last `max_bytes` of the log.
+ */
+async readLogFile(maxBytes: number | null) : Promise<Result<LogChunk, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("read_log_file", { maxBytes }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Read log lines written since `offset` in the file identified by `file_id`,
+ * both as returned by a previous `read_log_file` or `tail_log_file` call.
+ * Poll this to follow the log.
+ */
+async tailLogFile(offset: number, fileId: string) : Promise<Result<LogChunk, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("tail_log_file", { offset, fileId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Export a diagnostic bundle for bug reports into the Downloads folder and
+ * reveal it in the file manager. Returns the path of the zip.
+ */
+async exportDiagnostics() : Promise<Result<string, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("export_diagnostics") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 async openAppDataDir() : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("open_app_data_dir") };
@@ -669,6 +770,12 @@ async getModelLoadStatus() : Promise<Result<ModelLoadStatus, string>> {
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * Per-stage latency of recent transcriptions, newest first.
+ */
+async getLatencyMetrics() : Promise<LatencyRecord[]> {
+    return await TAURI_INVOKE("get_latency_metrics");
+},
 async unloadModelManually() : Promise<Result<null, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("unload_model_manually") };
@@ -725,6 +832,73 @@ async updateRecordingRetentionPeriod(period: string) : Promise<Result<null, stri
     else return { status: "error", error: e  as any };
 }
 },
+async getSendToConnectors() : Promise<Result<SendToConnector[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_send_to_connectors") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async addSendToConnector(connector: SendToConnector) : Promise<Result<SendToConnector, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("add_send_to_connector", { connector }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async updateSendToConnector(connector: SendToConnector) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("update_send_to_connector", { connector }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async deleteSendToConnector(id: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("delete_send_to_connector", { id }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Send a history entry to a connector. Uses the post-processed text when available.
+ */
+async sendHistoryEntryToConnector(id: number, connectorId: string) : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("send_history_entry_to_connector", { id, connectorId }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Report the status of every permission Handy relies on.
+ */
+async getPermissionsStatus() : Promise<Result<PermissionsReport, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_permissions_status") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Ask the OS for a permission. On macOS this shows the system prompt the first
+ * time, or opens the matching System Settings pane once the user has already
+ * answered. Returns the status after the request.
+ */
+async requestPermission(kind: PermissionKind) : Promise<Result<PermissionStatus, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("request_permission", { kind }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 /**
  * Checks if the Mac is a laptop by detecting battery presence
  * 
@@ -738,6 +912,46 @@ async isLaptop() : Promise<Result<boolean, string>> {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
 }
+},
+async getElevationStatus() : Promise<ElevationStatus> {
+    return await TAURI_INVOKE("get_elevation_status");
+},
+/**
+ * Relaunch Handy as Administrator so it can paste into elevated windows.
+ * Shows the UAC prompt; the current instance exits once the new one is started.
+ */
+async restartAsAdministrator() : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("restart_as_administrator") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Crash reports stored on this machine, newest first.
+ */
+async getCrashReports() : Promise<Result<CrashReport[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_crash_reports") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+async deleteCrashReports() : Promise<Result<null, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("delete_crash_reports") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * Whether this build can upload crash reports at all.
+ */
+async isCrashUploadAvailable() : Promise<boolean> {
+    return await TAURI_INVOKE("is_crash_upload_available");
 }
 }
 
@@ -751,35 +965,168 @@ async isLaptop() : Promise<Result<boolean, string>> {
 
 /** user-defined types **/
 
-export type AppSettings = { bindings: Partial<{ [key in string]: ShortcutBinding }>; push_to_talk: boolean; audio_feedback: boolean; audio_feedback_volume?: number; sound_theme?: SoundTheme; start_hidden?: boolean; autostart_enabled?: boolean; update_checks_enabled?: boolean; selected_model?: string; always_on_microphone?: boolean; selected_microphone?: string | null; clamshell_microphone?: string | null; selected_output_device?: string | null; translate_to_english?: boolean; selected_language?: string; overlay_position?: OverlayPosition; debug_mode?: boolean; log_level?: LogLevel; custom_words?: string[]; model_unload_timeout?: ModelUnloadTimeout; word_correction_threshold?: number; history_limit?: number; recording_retention_period?: RecordingRetentionPeriod; paste_method?: PasteMethod; clipboard_handling?: ClipboardHandling; post_process_enabled?: boolean; post_process_provider_id?: string; post_process_providers?: PostProcessProvider[]; post_process_api_keys?: Partial<{ [key in string]: string }>; post_process_models?: Partial<{ [key in string]: string }>; post_process_prompts?: LLMPrompt[]; post_process_selected_prompt_id?: string | null; mute_while_recording?: boolean; append_trailing_space?: boolean; app_language?: string; experimental_enabled?: boolean; keyboard_implementation?: KeyboardImplementation; paste_delay_ms?: number; stt_api?: SttApiSettings }
+export type AppSettings = { bindings: Partial<{ [key in string]: ShortcutBinding }>; push_to_talk: boolean; audio_feedback: boolean; audio_feedback_volume?: number; sound_theme?: SoundTheme; start_hidden?: boolean; autostart_enabled?: boolean; autostart_start_hidden?: boolean; update_checks_enabled?: boolean; update_channel?: UpdateChannel; crash_reporting_enabled?: boolean; selected_model?: string; always_on_microphone?: boolean; selected_microphone?: string | null; clamshell_microphone?: string | null; selected_output_device?: string | null; translate_to_english?: boolean; selected_language?: string; overlay_position?: OverlayPosition; debug_mode?: boolean; log_level?: LogLevel; custom_words?: string[]; model_unload_timeout?: ModelUnloadTimeout; word_correction_threshold?: number; history_limit?: number; recording_retention_period?: RecordingRetentionPeriod; paste_method?: PasteMethod; clipboard_handling?: ClipboardHandling; post_process_enabled?: boolean; post_process_provider_id?: string; post_process_providers?: PostProcessProvider[]; post_process_api_keys?: Partial<{ [key in string]: string }>; post_process_models?: Partial<{ [key in string]: string }>; post_process_prompts?: LLMPrompt[]; post_process_selected_prompt_id?: string | null; mute_while_recording?: boolean; append_trailing_space?: boolean; app_language?: string; experimental_enabled?: boolean; keyboard_implementation?: KeyboardImplementation; paste_delay_ms?: number; typing_tool?: TypingTool; stt_api?: SttApiSettings; send_to_connectors?: SendToConnector[] }
 export type AudioDevice = { index: string; name: string; is_default: boolean }
 export type BindingResponse = { success: boolean; binding: ShortcutBinding | null; error: string | null }
 export type ClipboardHandling = "dont_modify" | "copy_to_clipboard"
+export type CrashKind = "panic" | "native"
+export type CrashReport = { id: string; timestamp: number; kind: CrashKind; message: string; 
+/**
+ * Source location of the panic
+ */
+location: string | null; thread: string | null; backtrace: string | null; 
+/**
+ * Pipeline stage that was active when the crash happened
+ */
+stage: PipelineStage; app_version: string; os: string; os_version: string; arch: string; uploaded?: boolean }
 export type CustomSounds = { start: boolean; stop: boolean }
+export type ElevationStatus = { 
+/**
+ * Handy is running as Administrator
+ */
+process_elevated: boolean; 
+/**
+ * Handy was started with UIAccess, which bypasses UIPI
+ */
+ui_access: boolean }
 export type EngineType = "Whisper" | "Parakeet" | "Moonshine" | "Api"
-export type HistoryEntry = { id: number; file_name: string; timestamp: number; saved: boolean; title: string; transcription_text: string; post_processed_text: string | null; post_process_prompt: string | null }
+export type HistoryEntry = { id: number; file_name: string; timestamp: number; saved: boolean; title: string; transcription_text: string; post_processed_text: string | null; post_process_prompt: string | null; latency_metrics: LatencyMetrics | null }
 /**
  * Result of changing keyboard implementation
  */
+export type HttpHeader = { name: string; value: string }
+export type HttpMethod = "GET" | "POST" | "PUT" | "PATCH"
 export type ImplementationChangeResult = { success: boolean; 
 /**
  * List of binding IDs that were reset to defaults due to incompatibility
  */
 reset_bindings: string[] }
-export type KeyboardImplementation = "tauri" | "handy_keys"
+export type KeyboardImplementation = "tauri" | "handy_keys" | "wayland"
 export type LLMPrompt = { id: string; name: string; prompt: string }
+/**
+ * Stage durations of a single transcription, in milliseconds. Stages that
+ * didn't run (e.g. encoding with a local model) are `None`.
+ */
+export type LatencyMetrics = { 
+/**
+ * Length of the captured audio
+ */
+capture_ms: number; 
+/**
+ * Converting the samples to WAV for the STT API, summed over the chunks
+ * encoded during the upload
+ */
+encode_ms: number | null; 
+/**
+ * Sending the audio to the STT API
+ */
+upload_ms: number | null; 
+/**
+ * Local model inference, or waiting for the STT API to respond
+ */
+inference_ms: number; 
+/**
+ * Chinese variant conversion and LLM post-processing
+ */
+post_processing_ms: number | null; 
+/**
+ * Pasting or typing the text into the focused app
+ */
+insertion_ms: number | null; 
+/**
+ * From the end of recording until the text was inserted
+ */
+total_ms: number }
+export type LatencyRecord = { timestamp: number; metrics: LatencyMetrics }
+export type LogChunk = { 
+/**
+ * Log text, always starting and ending on a line boundary
+ */
+content: string; 
+/**
+ * Offset in the current log file to pass to the next `tail_log_file` call
+ */
+next_offset: number; 
+/**
+ * Identity of the file `next_offset` belongs to, passed back to
+ * `tail_log_file` along with it
+ */
+file_id: string; 
+/**
+ * The log was rotated since the given offset and reading restarted from
+ * the beginning of the new file
+ */
+rotated: boolean; 
+/**
+ * Path of the current log file
+ */
+path: string }
 export type LogLevel = "trace" | "debug" | "info" | "warn" | "error"
 export type ModelInfo = { id: string; name: string; description: string; filename: string; url: string | null; size_mb: number; is_downloaded: boolean; is_downloading: boolean; partial_size: number; is_directory: boolean; engine_type: EngineType; accuracy_score: number; speed_score: number }
 export type ModelLoadStatus = { is_loaded: boolean; current_model: string | null }
 export type ModelUnloadTimeout = "never" | "immediately" | "min_2" | "min_5" | "min_10" | "min_15" | "hour_1" | "sec_5"
 export type OverlayPosition = "none" | "top" | "bottom"
 export type PasteMethod = "ctrl_v" | "direct" | "none" | "shift_insert" | "ctrl_shift_v"
+export type PermissionKind = "microphone" | "accessibility" | "input_monitoring"
+export type PermissionStatus = "granted" | "denied" | 
+/**
+ * The platform doesn't gate this capability behind a permission
+ */
+"not_required"
+export type PermissionsReport = { microphone: PermissionStatus; 
+/**
+ * Needed to paste transcriptions via synthetic key events
+ */
+accessibility: PermissionStatus; 
+/**
+ * Needed by the handy-keys shortcut implementation to listen for key events
+ */
+input_monitoring: PermissionStatus; 
+/**
+ * True when every permission needed for recording, pasting and the selected
+ * shortcut implementation is available
+ */
+all_granted: boolean }
+export type PipelineStage = "idle" | "recording" | 
+/**
+ * Local inference or a request to the STT API
+ */
+"transcribing" | "post_processing" | "pasting"
 export type PostProcessProvider = { id: string; label: string; base_url: string; allow_base_url_edit?: boolean; models_endpoint?: string | null }
 export type RecordingRetentionPeriod = "never" | "preserve_limit" | "days_3" | "weeks_2" | "months_3"
+/**
+ * A user-defined "send to" destination described as an HTTP request template.
+ * `{text}` in the URL or body is replaced with the transcript when the request is sent.
+ */
+export type SendToConnector = { id: string; name: string; method: HttpMethod; url: string; headers?: HttpHeader[]; body_template?: string; 
+/**
+ * Send every completed transcription to this connector automatically.
+ */
+auto_send?: boolean }
 export type ShortcutBinding = { id: string; name: string; description: string; default_binding: string; current_binding: string }
 export type SoundTheme = "marimba" | "pop" | "custom"
 export type SttApiProvider = { id: string; label: string; base_url: string; allow_base_url_edit?: boolean }
 export type SttApiSettings = { enabled: boolean; provider_id: string; providers: SttApiProvider[]; api_keys: Partial<{ [key in string]: string }>; models: Partial<{ [key in string]: string }> }
+/**
+ * Tool used to inject keystrokes and text on Linux. `Auto` picks one based on the
+ * session type (wtype/dotool/ydotool on Wayland, xdotool on X11).
+ */
+export type TypingTool = "auto" | "wtype" | "kwtype" | "dotool" | "ydotool" | "xdotool" | "enigo"
+/**
+ * Which releases the update checker offers. `Beta` also includes pre-releases.
+ */
+export type UpdateChannel = "stable" | "beta"
+export type UpdateDownloadProgress = { downloaded: number; total: number | null }
+export type UpdateInfo = { version: string; current_version: string; 
+/**
+ * Release notes
+ */
+notes: string | null; date: string | null; channel: UpdateChannel; 
+/**
+ * The update can be downloaded and applied in place. When false it has to
+ * be installed from the release page (e.g. .deb/.rpm installs on Linux).
+ */
+can_install: boolean; release_url: string }
 
 /** tauri-specta globals **/
 
@@ -1,9 +1,7 @@
 import { useEffect, useState } from "react";
 import { useTranslation } from "react-i18next";
-import {
-  checkAccessibilityPermission,
-  requestAccessibilityPermission,
-} from "tauri-plugin-macos-permissions-api";
+import { commands } from "@/bindings";
+import { isPermissionSatisfied } from "@/lib/utils/permissions";
 
 // Define permission state type
 type PermissionState = "request" | "verify" | "granted";
@@ -14,6 +12,13 @@ interface ButtonConfig {
   className: string;
 }
 
+const checkAccessibilityPermission = async (): Promise<boolean> => {
+  const result = await commands.getPermissionsStatus();
+  return (
+    result.status === "ok" && isPermissionSatisfied(result.data.accessibility)
+  );
+};
+
 const AccessibilityPermissions: React.FC = () => {
   const { t } = useTranslation();
   const [hasAccessibility, setHasAccessibility] = useState<boolean>(false);
@@ -32,7 +37,7 @@ const AccessibilityPermissions: React.FC = () => {
   const handleButtonClick = async (): Promise<void> => {
     if (permissionState === "request") {
       try {
-        await requestAccessibilityPermission();
+        await commands.requestPermission("accessibility");
         // After system prompt, transition to verification state
         setPermissionState("verify");
       } catch (error) {
@@ -1,14 +1,9 @@
 import { useEffect, useState, useCallback, useRef } from "react";
 import { useTranslation } from "react-i18next";
 import { platform } from "@tauri-apps/plugin-os";
-import {
-  checkAccessibilityPermission,
-  requestAccessibilityPermission,
-  checkMicrophonePermission,
-  requestMicrophonePermission,
-} from "tauri-plugin-macos-permissions-api";
 import { toast } from "sonner";
-import { commands } from "@/bindings";
+import { commands, type PermissionKind } from "@/bindings";
+import { isPermissionSatisfied } from "@/lib/utils/permissions";
 import { useSettingsStore } from "@/stores/settingsStore";
 import HandyTextLogo from "../icons/HandyTextLogo";
 import { Keyboard, Mic, Check, Loader2 } from "lucide-react";
@@ -24,6 +19,25 @@ interface PermissionsState {
   microphone: PermissionStatus;
 }
 
+// Resolves to whether accessibility and microphone access are available
+const checkPermissions = async () => {
+  const result = await commands.getPermissionsStatus();
+  if (result.status === "error") {
+    throw new Error(result.error);
+  }
+  return [
+    isPermissionSatisfied(result.data.accessibility),
+    isPermissionSatisfied(result.data.microphone),
+  ] as const;
+};
+
+const requestPermission = async (kind: PermissionKind) => {
+  const result = await commands.requestPermission(kind);
+  if (result.status === "error") {
+    throw new Error(result.error);
+  }
+};
+
 const AccessibilityOnboarding: React.FC<AccessibilityOnboardingProps> = ({
   onComplete,
 }) => {
@@ -63,10 +77,8 @@ const AccessibilityOnboarding: React.FC<AccessibilityOnboardingProps> = ({
     // On macOS, check both permissions
     const checkInitial = async () => {
       try {
-        const [accessibilityGranted, microphoneGranted] = await Promise.all([
-          checkAccessibilityPermission(),
-          checkMicrophonePermission(),
-        ]);
+        const [accessibilityGranted, microphoneGranted] =
+          await checkPermissions();
 
         // If accessibility is granted, initialize Enigo and shortcuts
         if (accessibilityGranted) {
@@ -111,10 +123,8 @@ const AccessibilityOnboarding: React.FC<AccessibilityOnboardingProps> = ({
 
     pollingRef.current = setInterval(async () => {
       try {
-        const [accessibilityGranted, microphoneGranted] = await Promise.all([
-          checkAccessibilityPermission(),
-          checkMicrophonePermission(),
-        ]);
+        const [accessibilityGranted, microphoneGranted] =
+          await checkPermissions();
 
         setPermissions((prev) => {
           const newState = { ...prev };
@@ -180,7 +190,7 @@ const AccessibilityOnboarding: React.FC<AccessibilityOnboardingProps> = ({
 
   const handleGrantAccessibility = async () => {
     try {
-      await requestAccessibilityPermission();
+      await requestPermission("accessibility");
       setPermissions((prev) => ({ ...prev, accessibility: "waiting" }));
       startPolling();
     } catch (error) {
@@ -191,7 +201,7 @@ const AccessibilityOnboarding: React.FC<AccessibilityOnboardingProps> = ({
 
   const handleGrantMicrophone = async () => {
     try {
-      await requestMicrophonePermission();
+      await requestPermission("microphone");
       setPermissions((prev) => ({ ...prev, microphone: "waiting" }));
       startPolling();
     } catch (error) {
@@ -0,0 +1,31 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { ToggleSwitch } from "../ui/ToggleSwitch";
+import { useSettings } from "../../hooks/useSettings";
+
+interface AutostartStartHiddenProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const AutostartStartHidden: React.FC<AutostartStartHiddenProps> =
+  React.memo(({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const { getSetting, updateSetting, isUpdating } = useSettings();
+
+    const autostartEnabled = getSetting("autostart_enabled") ?? false;
+    const startHidden = getSetting("autostart_start_hidden") ?? false;
+
+    return (
+      <ToggleSwitch
+        checked={startHidden}
+        onChange={(enabled) => updateSetting("autostart_start_hidden", enabled)}
+        isUpdating={isUpdating("autostart_start_hidden")}
+        disabled={!autostartEnabled}
+        label={t("settings.advanced.autostartStartHidden.label")}
+        description={t("settings.advanced.autostartStartHidden.description")}
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+      />
+    );
+  });
@@ -0,0 +1,307 @@
+import React, { useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { toast } from "sonner";
+import {
+  commands,
+  type HttpHeader,
+  type HttpMethod,
+  type SendToConnector,
+} from "@/bindings";
+import {
+  Dropdown,
+  SettingContainer,
+  Textarea,
+  ToggleSwitch,
+} from "@/components/ui";
+import { Button } from "../ui/Button";
+import { Input } from "../ui/Input";
+import { useSettings } from "../../hooks/useSettings";
+
+const HTTP_METHODS: HttpMethod[] = ["POST", "PUT", "PATCH", "GET"];
+
+interface ConnectorDraft {
+  name: string;
+  method: HttpMethod;
+  url: string;
+  headers: string;
+  bodyTemplate: string;
+  autoSend: boolean;
+}
+
+const EMPTY_DRAFT: ConnectorDraft = {
+  name: "",
+  method: "POST",
+  url: "",
+  headers: "Content-Type: application/json",
+  bodyTemplate: '{"text": "{text}"}',
+  autoSend: false,
+};
+
+// Headers are edited as one "Name: value" pair per line
+const formatHeaders = (headers: HttpHeader[]) =>
+  headers.map((header) => `${header.name}: ${header.value}`).join("\n");
+
+const parseHeaders = (text: string): HttpHeader[] =>
+  text
+    .split("\n")
+    .map((line) => line.trim())
+    .filter((line) => line.length > 0)
+    .map((line) => {
+      const separator = line.indexOf(":");
+      if (separator === -1) return { name: line, value: "" };
+      return {
+        name: line.slice(0, separator).trim(),
+        value: line.slice(separator + 1).trim(),
+      };
+    });
+
+const toDraft = (connector: SendToConnector): ConnectorDraft => ({
+  name: connector.name,
+  method: connector.method,
+  url: connector.url,
+  headers: formatHeaders(connector.headers ?? []),
+  bodyTemplate: connector.body_template ?? "",
+  autoSend: connector.auto_send ?? false,
+});
+
+const fromDraft = (id: string, draft: ConnectorDraft): SendToConnector => ({
+  id,
+  name: draft.name.trim(),
+  method: draft.method,
+  url: draft.url.trim(),
+  headers: parseHeaders(draft.headers),
+  body_template: draft.bodyTemplate,
+  auto_send: draft.autoSend,
+});
+
+interface SendToConnectorsProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const SendToConnectors: React.FC<SendToConnectorsProps> = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}) => {
+  const { t } = useTranslation();
+  const { getSetting, refreshSettings } = useSettings();
+  const [selectedId, setSelectedId] = useState<string | null>(null);
+  const [isCreating, setIsCreating] = useState(false);
+  const [draft, setDraft] = useState<ConnectorDraft>(EMPTY_DRAFT);
+
+  const connectors = getSetting("send_to_connectors") || [];
+  const selectedConnector =
+    connectors.find((connector) => connector.id === selectedId) || null;
+
+  useEffect(() => {
+    if (isCreating) return;
+    setDraft(selectedConnector ? toDraft(selectedConnector) : EMPTY_DRAFT);
+  }, [isCreating, selectedConnector]);
+
+  const updateDraft = <K extends keyof ConnectorDraft>(
+    key: K,
+    value: ConnectorDraft[K],
+  ) => setDraft((prev) => ({ ...prev, [key]: value }));
+
+  const handleCreate = async () => {
+    const result = await commands.addSendToConnector(fromDraft("", draft));
+    if (result.status === "ok") {
+      await refreshSettings();
+      setSelectedId(result.data.id);
+      setIsCreating(false);
+    } else {
+      toast.error(result.error);
+    }
+  };
+
+  const handleUpdate = async () => {
+    if (!selectedConnector) return;
+    const result = await commands.updateSendToConnector(
+      fromDraft(selectedConnector.id, draft),
+    );
+    if (result.status === "ok") {
+      await refreshSettings();
+    } else {
+      toast.error(result.error);
+    }
+  };
+
+  const handleDelete = async () => {
+    if (!selectedConnector) return;
+    const result = await commands.deleteSendToConnector(selectedConnector.id);
+    if (result.status === "ok") {
+      await refreshSettings();
+      setSelectedId(null);
+    } else {
+      toast.error(result.error);
+    }
+  };
+
+  const handleStartCreate = () => {
+    setIsCreating(true);
+    setDraft(EMPTY_DRAFT);
+  };
+
+  const canSave = draft.name.trim() !== "" && draft.url.trim() !== "";
+  const showEditor = isCreating || selectedConnector !== null;
+
+  return (
+    <SettingContainer
+      title={t("settings.advanced.sendTo.title")}
+      description={t("settings.advanced.sendTo.description")}
+      descriptionMode={descriptionMode}
+      grouped={grouped}
+      layout="stacked"
+    >
+      <div className="space-y-3">
+        <div className="flex gap-2">
+          <Dropdown
+            selectedValue={selectedId}
+            options={connectors.map((connector) => ({
+              value: connector.id,
+              label: connector.name,
+            }))}
+            onSelect={(value) => {
+              setSelectedId(value);
+              setIsCreating(false);
+            }}
+            placeholder={
+              connectors.length === 0
+                ? t("settings.advanced.sendTo.noConnectors")
+                : t("settings.advanced.sendTo.selectConnector")
+            }
+            disabled={isCreating || connectors.length === 0}
+            className="flex-1"
+          />
+          <Button
+            onClick={handleStartCreate}
+            variant="primary"
+            size="md"
+            disabled={isCreating}
+          >
+            {t("settings.advanced.sendTo.createNew")}
+          </Button>
+        </div>
+
+        {showEditor && (
+          <div className="space-y-3">
+            <div className="space-y-2 flex flex-col">
+              <label className="text-sm font-semibold">
+                {t("settings.advanced.sendTo.name")}
+              </label>
+              <Input
+                type="text"
+                value={draft.name}
+                onChange={(e) => updateDraft("name", e.target.value)}
+                placeholder={t("settings.advanced.sendTo.namePlaceholder")}
+                variant="compact"
+              />
+            </div>
+
+            <div className="space-y-2 flex flex-col">
+              <label className="text-sm font-semibold">
+                {t("settings.advanced.sendTo.request")}
+              </label>
+              <div className="flex gap-2">
+                <Dropdown
+                  selectedValue={draft.method}
+                  options={HTTP_METHODS.map((method) => ({
+                    value: method,
+                    label: method,
+                  }))}
+                  onSelect={(value) =>
+                    updateDraft("method", value as HttpMethod)
+                  }
+                />
+                <Input
+                  type="text"
+                  value={draft.url}
+                  onChange={(e) => updateDraft("url", e.target.value)}
+                  placeholder={t("settings.advanced.sendTo.urlPlaceholder")}
+                  variant="compact"
+                  className="flex-1"
+                />
+              </div>
+            </div>
+
+            <div className="space-y-2 flex flex-col">
+              <label className="text-sm font-semibold">
+                {t("settings.advanced.sendTo.headers")}
+              </label>
+              <Textarea
+                value={draft.headers}
+                onChange={(e) => updateDraft("headers", e.target.value)}
+                placeholder={t("settings.advanced.sendTo.headersPlaceholder")}
+                variant="compact"
+              />
+            </div>
+
+            {draft.method !== "GET" && (
+              <div className="space-y-2 flex flex-col">
+                <label className="text-sm font-semibold">
+                  {t("settings.advanced.sendTo.body")}
+                </label>
+                <Textarea
+                  value={draft.bodyTemplate}
+                  onChange={(e) => updateDraft("bodyTemplate", e.target.value)}
+                  variant="compact"
+                />
+              </div>
+            )}
+            <p className="text-xs text-mid-gray/70">
+              {t("settings.advanced.sendTo.placeholderTip")}
+            </p>
+
+            <ToggleSwitch
+              checked={draft.autoSend}
+              onChange={(checked) => updateDraft("autoSend", checked)}
+              label={t("settings.advanced.sendTo.autoSend")}
+              description={t("settings.advanced.sendTo.autoSendDescription")}
+              grouped={true}
+            />
+
+            <div className="flex gap-2 pt-2">
+              {isCreating ? (
+                <>
+                  <Button
+                    onClick={handleCreate}
+                    variant="primary"
+                    size="md"
+                    disabled={!canSave}
+                  >
+                    {t("settings.advanced.sendTo.create")}
+                  </Button>
+                  <Button
+                    onClick={() => setIsCreating(false)}
+                    variant="secondary"
+                    size="md"
+                  >
+                    {t("settings.advanced.sendTo.cancel")}
+                  </Button>
+                </>
+              ) : (
+                <>
+                  <Button
+                    onClick={handleUpdate}
+                    variant="primary"
+                    size="md"
+                    disabled={!canSave}
+                  >
+                    {t("settings.advanced.sendTo.update")}
+                  </Button>
+                  <Button
+                    onClick={handleDelete}
+                    variant="secondary"
+                    size="md"
+                  >
+                    {t("settings.advanced.sendTo.delete")}
+                  </Button>
+                </>
+              )}
+            </div>
+          </div>
+        )}
+      </div>
+    </SettingContainer>
+  );
+};
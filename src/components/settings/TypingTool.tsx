@@ -0,0 +1,52 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { Dropdown } from "../ui/Dropdown";
+import { SettingContainer } from "../ui/SettingContainer";
+import { useSettings } from "../../hooks/useSettings";
+import type { TypingTool } from "@/bindings";
+
+interface TypingToolProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const TypingToolSetting: React.FC<TypingToolProps> = React.memo(
+  ({ descriptionMode = "tooltip", grouped = false }) => {
+    const { t } = useTranslation();
+    const { getSetting, updateSetting, isUpdating } = useSettings();
+
+    const typingToolOptions = [
+      {
+        value: "auto",
+        label: t("settings.advanced.typingTool.options.auto"),
+      },
+      { value: "wtype", label: "wtype" },
+      { value: "kwtype", label: "kwtype" },
+      { value: "dotool", label: "dotool" },
+      { value: "ydotool", label: "ydotool" },
+      { value: "xdotool", label: "xdotool" },
+      {
+        value: "enigo",
+        label: t("settings.advanced.typingTool.options.enigo"),
+      },
+    ];
+
+    const selectedTool = (getSetting("typing_tool") || "auto") as TypingTool;
+
+    return (
+      <SettingContainer
+        title={t("settings.advanced.typingTool.title")}
+        description={t("settings.advanced.typingTool.description")}
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+      >
+        <Dropdown
+          options={typingToolOptions}
+          selectedValue={selectedTool}
+          onSelect={(value) => updateSetting("typing_tool", value as TypingTool)}
+          disabled={isUpdating("typing_tool")}
+        />
+      </SettingContainer>
+    );
+  },
+);
@@ -0,0 +1,51 @@
+import React from "react";
+import { useTranslation } from "react-i18next";
+import { Dropdown } from "../ui/Dropdown";
+import { SettingContainer } from "../ui/SettingContainer";
+import { useSettings } from "../../hooks/useSettings";
+import type { UpdateChannel } from "@/bindings";
+
+interface UpdateChannelSelectorProps {
+  descriptionMode?: "inline" | "tooltip";
+  grouped?: boolean;
+}
+
+export const UpdateChannelSelector: React.FC<UpdateChannelSelectorProps> = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}) => {
+  const { t } = useTranslation();
+  const { getSetting, updateSetting, isUpdating } = useSettings();
+  const selectedChannel = (getSetting("update_channel") ||
+    "stable") as UpdateChannel;
+  const updateChecksEnabled = getSetting("update_checks_enabled") ?? true;
+
+  const options = [
+    {
+      value: "stable",
+      label: t("settings.debug.updateChannel.options.stable"),
+    },
+    {
+      value: "beta",
+      label: t("settings.debug.updateChannel.options.beta"),
+    },
+  ];
+
+  return (
+    <SettingContainer
+      title={t("settings.debug.updateChannel.title")}
+      description={t("settings.debug.updateChannel.description")}
+      descriptionMode={descriptionMode}
+      grouped={grouped}
+    >
+      <Dropdown
+        options={options}
+        selectedValue={selectedChannel}
+        onSelect={(value) =>
+          updateSetting("update_channel", value as UpdateChannel)
+        }
+        disabled={!updateChecksEnabled || isUpdating("update_channel")}
+      />
+    </SettingContainer>
+  );
+};
@@ -7,13 +7,17 @@ import { CustomWords } from "../CustomWords";
 import { SettingsGroup } from "../../ui/SettingsGroup";
 import { StartHidden } from "../StartHidden";
 import { AutostartToggle } from "../AutostartToggle";
+import { AutostartStartHidden } from "../AutostartStartHidden";
 import { PasteMethodSetting } from "../PasteMethod";
 import { ClipboardHandlingSetting } from "../ClipboardHandling";
+import { TypingToolSetting } from "../TypingTool";
+import { useOsType } from "@/hooks/useOsType";
 import { useModelStore } from "../../../stores/modelStore";
 import { PostProcessingToggle } from "../PostProcessingToggle";
 import { AppendTrailingSpace } from "../AppendTrailingSpace";
 import { HistoryLimit } from "../HistoryLimit";
 import { RecordingRetentionPeriodSelector } from "../RecordingRetentionPeriod";
+import { SendToConnectors } from "../SendToConnectors";
 import { ExperimentalToggle } from "../ExperimentalToggle";
 import { useSettings } from "../../../hooks/useSettings";
 import { KeyboardImplementationSelector } from "../debug/KeyboardImplementationSelector";
@@ -26,12 +30,14 @@ export const AdvancedSettings: React.FC = () => {
   const showTranslateToEnglish =
     currentModelInfo?.engine_type === "Whisper" && currentModel !== "turbo";
   const experimentalEnabled = getSetting("experimental_enabled") || false;
+  const osType = useOsType();
 
   return (
     <div className="max-w-3xl w-full mx-auto space-y-6">
       <SettingsGroup title={t("settings.advanced.groups.app")}>
         <StartHidden descriptionMode="tooltip" grouped={true} />
         <AutostartToggle descriptionMode="tooltip" grouped={true} />
+        <AutostartStartHidden descriptionMode="tooltip" grouped={true} />
         <ShowOverlay descriptionMode="tooltip" grouped={true} />
         <ModelUnloadTimeoutSetting descriptionMode="tooltip" grouped={true} />
         <ExperimentalToggle descriptionMode="tooltip" grouped={true} />
@@ -40,6 +46,9 @@ export const AdvancedSettings: React.FC = () => {
       <SettingsGroup title={t("settings.advanced.groups.output")}>
         <PasteMethodSetting descriptionMode="tooltip" grouped={true} />
         <ClipboardHandlingSetting descriptionMode="tooltip" grouped={true} />
+        {osType === "linux" && (
+          <TypingToolSetting descriptionMode="tooltip" grouped={true} />
+        )}
       </SettingsGroup>
 
       <SettingsGroup title={t("settings.advanced.groups.transcription")}>
@@ -58,6 +67,10 @@ export const AdvancedSettings: React.FC = () => {
         />
       </SettingsGroup>
 
+      <SettingsGroup title={t("settings.advanced.groups.sendTo")}>
+        <SendToConnectors descriptionMode="tooltip" grouped={true} />
+      </SettingsGroup>
+
       {experimentalEnabled && (
         <SettingsGroup title={t("settings.advanced.groups.experimental")}>
           <PostProcessingToggle descriptionMode="tooltip" grouped={true} />
@@ -0,0 +1,84 @@
+import React, { useCallback, useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { toast } from "sonner";
+import { commands, type CrashReport } from "@/bindings";
+import { SettingContainer } from "../../ui/SettingContainer";
+import { ToggleSwitch } from "../../ui/ToggleSwitch";
+import { Button } from "../../ui/Button";
+import { useSettings } from "../../../hooks/useSettings";
+
+interface CrashReportingProps {
+  descriptionMode?: "tooltip" | "inline";
+  grouped?: boolean;
+}
+
+export const CrashReporting: React.FC<CrashReportingProps> = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}) => {
+  const { t } = useTranslation();
+  const { getSetting, updateSetting, isUpdating } = useSettings();
+  const [reports, setReports] = useState<CrashReport[]>([]);
+  const [uploadAvailable, setUploadAvailable] = useState(false);
+
+  const enabled = getSetting("crash_reporting_enabled") ?? false;
+
+  const loadReports = useCallback(async () => {
+    const result = await commands.getCrashReports();
+    if (result.status === "ok") {
+      setReports(result.data);
+    }
+  }, []);
+
+  useEffect(() => {
+    loadReports();
+    commands.isCrashUploadAvailable().then(setUploadAvailable);
+  }, [loadReports]);
+
+  const handleDelete = async () => {
+    const result = await commands.deleteCrashReports();
+    if (result.status === "ok") {
+      setReports([]);
+    } else {
+      toast.error(result.error);
+    }
+  };
+
+  return (
+    <>
+      <ToggleSwitch
+        checked={enabled}
+        onChange={(value) => updateSetting("crash_reporting_enabled", value)}
+        isUpdating={isUpdating("crash_reporting_enabled")}
+        label={t("settings.debug.crashReporting.label")}
+        description={
+          uploadAvailable
+            ? t("settings.debug.crashReporting.description")
+            : t("settings.debug.crashReporting.descriptionLocalOnly")
+        }
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+      />
+      <SettingContainer
+        title={t("settings.debug.crashReports.title")}
+        description={t("settings.debug.crashReports.description")}
+        descriptionMode={descriptionMode}
+        grouped={grouped}
+      >
+        <div className="flex items-center gap-3">
+          <span className="text-sm text-mid-gray">
+            {t("settings.debug.crashReports.count", { count: reports.length })}
+          </span>
+          <Button
+            variant="secondary"
+            size="md"
+            onClick={handleDelete}
+            disabled={reports.length === 0}
+          >
+            {t("settings.debug.crashReports.delete")}
+          </Button>
+        </div>
+      </SettingContainer>
+    </>
+  );
+};
@@ -0,0 +1,55 @@
+import React, { useState } from "react";
+import { useTranslation } from "react-i18next";
+import { toast } from "sonner";
+import { commands } from "@/bindings";
+import { SettingContainer } from "../../ui/SettingContainer";
+import { Button } from "../../ui/Button";
+
+interface ExportDiagnosticsProps {
+  descriptionMode?: "tooltip" | "inline";
+  grouped?: boolean;
+}
+
+export const ExportDiagnostics: React.FC<ExportDiagnosticsProps> = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}) => {
+  const { t } = useTranslation();
+  const [exporting, setExporting] = useState(false);
+
+  const handleExport = async () => {
+    setExporting(true);
+    try {
+      const result = await commands.exportDiagnostics();
+      if (result.status === "ok") {
+        toast.success(
+          t("settings.debug.exportDiagnostics.success", { path: result.data }),
+        );
+      } else {
+        toast.error(result.error);
+      }
+    } catch (error) {
+      toast.error(String(error));
+    } finally {
+      setExporting(false);
+    }
+  };
+
+  return (
+    <SettingContainer
+      title={t("settings.debug.exportDiagnostics.title")}
+      description={t("settings.debug.exportDiagnostics.description")}
+      descriptionMode={descriptionMode}
+      grouped={grouped}
+    >
+      <Button
+        variant="secondary"
+        size="md"
+        onClick={handleExport}
+        disabled={exporting}
+      >
+        {t("settings.debug.exportDiagnostics.button")}
+      </Button>
+    </SettingContainer>
+  );
+};
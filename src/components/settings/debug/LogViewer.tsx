@@ -0,0 +1,153 @@
+import React, { useCallback, useEffect, useRef, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { commands, type LogLevel } from "@/bindings";
+import { SettingContainer } from "../../ui/SettingContainer";
+import { Button } from "../../ui/Button";
+import { Dropdown } from "../../ui/Dropdown";
+
+// Keep roughly the last 256 KB of log text in the viewer
+const MAX_VISIBLE_CHARS = 256 * 1024;
+const POLL_INTERVAL_MS = 1000;
+// Dropdown value for following RUST_LOG instead of an explicit console level
+const CONSOLE_LEVEL_FROM_ENV = "env";
+const CONSOLE_LEVELS: LogLevel[] = ["error", "warn", "info", "debug", "trace"];
+
+interface LogViewerProps {
+  descriptionMode?: "tooltip" | "inline";
+  grouped?: boolean;
+}
+
+export const LogViewer: React.FC<LogViewerProps> = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}) => {
+  const { t } = useTranslation();
+  const [content, setContent] = useState("");
+  const [error, setError] = useState<string | null>(null);
+  const [following, setFollowing] = useState(false);
+  const [consoleLevel, setConsoleLevel] = useState<string>(
+    CONSOLE_LEVEL_FROM_ENV,
+  );
+  // Where the next tail read starts, and which log file that offset is in
+  const positionRef = useRef({ offset: 0, fileId: "" });
+  const outputRef = useRef<HTMLPreElement>(null);
+
+  const loadLog = useCallback(async () => {
+    const result = await commands.readLogFile(null);
+    if (result.status === "ok") {
+      positionRef.current = {
+        offset: result.data.next_offset,
+        fileId: result.data.file_id,
+      };
+      setContent(result.data.content);
+      setError(null);
+    } else {
+      setError(result.error);
+    }
+  }, []);
+
+  useEffect(() => {
+    loadLog();
+    commands
+      .getConsoleLogLevel()
+      .then((level) => setConsoleLevel(level ?? CONSOLE_LEVEL_FROM_ENV));
+  }, [loadLog]);
+
+  const handleConsoleLevelSelect = async (value: string) => {
+    await commands.setConsoleLogLevel(
+      value === CONSOLE_LEVEL_FROM_ENV ? null : (value as LogLevel),
+    );
+    setConsoleLevel(value);
+  };
+
+  useEffect(() => {
+    if (!following) return;
+
+    const interval = setInterval(async () => {
+      const { offset, fileId } = positionRef.current;
+      const result = await commands.tailLogFile(offset, fileId);
+      if (result.status !== "ok") {
+        setError(result.error);
+        return;
+      }
+      positionRef.current = {
+        offset: result.data.next_offset,
+        fileId: result.data.file_id,
+      };
+      const { content: chunk, rotated } = result.data;
+      if (!chunk && !rotated) return;
+      setContent((prev) => {
+        const next = rotated ? chunk : prev + chunk;
+        return next.length > MAX_VISIBLE_CHARS
+          ? next.slice(next.length - MAX_VISIBLE_CHARS)
+          : next;
+      });
+    }, POLL_INTERVAL_MS);
+
+    return () => clearInterval(interval);
+  }, [following]);
+
+  useEffect(() => {
+    if (outputRef.current) {
+      outputRef.current.scrollTop = outputRef.current.scrollHeight;
+    }
+  }, [content]);
+
+  return (
+    <SettingContainer
+      title={t("settings.debug.logViewer.title")}
+      description={t("settings.debug.logViewer.description")}
+      descriptionMode={descriptionMode}
+      grouped={grouped}
+      layout="stacked"
+    >
+      <div className="space-y-2">
+        <div className="flex gap-2">
+          <Button variant="secondary" size="sm" onClick={loadLog}>
+            {t("settings.debug.logViewer.refresh")}
+          </Button>
+          <Button
+            variant={following ? "primary" : "secondary"}
+            size="sm"
+            onClick={() => setFollowing((value) => !value)}
+          >
+            {following
+              ? t("settings.debug.logViewer.stopFollowing")
+              : t("settings.debug.logViewer.follow")}
+          </Button>
+          <div className="flex items-center gap-2 ms-auto">
+            <span className="text-xs text-mid-gray">
+              {t("settings.debug.logViewer.consoleLevel")}
+            </span>
+            <Dropdown
+              options={[
+                {
+                  value: CONSOLE_LEVEL_FROM_ENV,
+                  label: t("settings.debug.logViewer.consoleLevelFromEnv"),
+                },
+                ...CONSOLE_LEVELS.map((level) => ({
+                  value: level,
+                  label: level.charAt(0).toUpperCase() + level.slice(1),
+                })),
+              ]}
+              selectedValue={consoleLevel}
+              onSelect={handleConsoleLevelSelect}
+            />
+          </div>
+        </div>
+        {error ? (
+          <div className="p-3 bg-red-50 border border-red-200 rounded text-xs text-red-600">
+            {error}
+          </div>
+        ) : (
+          <pre
+            ref={outputRef}
+            className="h-64 overflow-auto p-2 rounded border border-mid-gray/20 bg-mid-gray/5 text-xs font-mono whitespace-pre-wrap break-all select-text"
+          >
+            {content || t("settings.debug.logViewer.empty")}
+          </pre>
+        )}
+      </div>
+    </SettingContainer>
+  );
+};
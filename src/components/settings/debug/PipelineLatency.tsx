@@ -0,0 +1,120 @@
+import React, { useCallback, useEffect, useState } from "react";
+import { useTranslation } from "react-i18next";
+import { commands, type LatencyRecord } from "@/bindings";
+import { SettingContainer } from "../../ui/SettingContainer";
+import { Button } from "../../ui/Button";
+import { formatDateTime } from "@/utils/dateFormat";
+
+const formatMs = (value: number | null) => (value === null ? "–" : `${value}`);
+
+interface PipelineLatencyProps {
+  descriptionMode?: "tooltip" | "inline";
+  grouped?: boolean;
+}
+
+export const PipelineLatency: React.FC<PipelineLatencyProps> = ({
+  descriptionMode = "tooltip",
+  grouped = false,
+}) => {
+  const { t, i18n } = useTranslation();
+  const [records, setRecords] = useState<LatencyRecord[]>([]);
+
+  const loadRecords = useCallback(async () => {
+    // Already newest first
+    setRecords(await commands.getLatencyMetrics());
+  }, []);
+
+  useEffect(() => {
+    loadRecords();
+  }, [loadRecords]);
+
+  // Encoding and upload only happen with the STT API
+  const showEncode = records.some(({ metrics }) => metrics.encode_ms !== null);
+  const showUpload = records.some(({ metrics }) => metrics.upload_ms !== null);
+
+  return (
+    <SettingContainer
+      title={t("settings.debug.pipelineLatency.title")}
+      description={t("settings.debug.pipelineLatency.description")}
+      descriptionMode={descriptionMode}
+      grouped={grouped}
+      layout="stacked"
+    >
+      <div className="space-y-2">
+        <Button variant="secondary" size="sm" onClick={loadRecords}>
+          {t("settings.debug.pipelineLatency.refresh")}
+        </Button>
+        {records.length === 0 ? (
+          <p className="text-xs text-mid-gray">
+            {t("settings.debug.pipelineLatency.empty")}
+          </p>
+        ) : (
+          <div className="max-h-64 overflow-auto rounded border border-mid-gray/20">
+            <table className="w-full text-xs font-mono">
+              <thead className="bg-mid-gray/10 text-left">
+                <tr>
+                  <th className="p-2">
+                    {t("settings.debug.pipelineLatency.time")}
+                  </th>
+                  <th className="p-2 text-right">
+                    {t("settings.debug.pipelineLatency.audio")}
+                  </th>
+                  {showEncode && (
+                    <th className="p-2 text-right">
+                      {t("settings.debug.pipelineLatency.encode")}
+                    </th>
+                  )}
+                  {showUpload && (
+                    <th className="p-2 text-right">
+                      {t("settings.debug.pipelineLatency.upload")}
+                    </th>
+                  )}
+                  <th className="p-2 text-right">
+                    {t("settings.debug.pipelineLatency.inference")}
+                  </th>
+                  <th className="p-2 text-right">
+                    {t("settings.debug.pipelineLatency.postProcessing")}
+                  </th>
+                  <th className="p-2 text-right">
+                    {t("settings.debug.pipelineLatency.insertion")}
+                  </th>
+                  <th className="p-2 text-right">
+                    {t("settings.debug.pipelineLatency.total")}
+                  </th>
+                </tr>
+              </thead>
+              <tbody>
+                {records.map(({ timestamp, metrics }, index) => (
+                  <tr key={index} className="border-t border-mid-gray/10">
+                    <td className="p-2">
+                      {formatDateTime(String(timestamp), i18n.language)}
+                    </td>
+                    <td className="p-2 text-right">{metrics.capture_ms}</td>
+                    {showEncode && (
+                      <td className="p-2 text-right">
+                        {formatMs(metrics.encode_ms)}
+                      </td>
+                    )}
+                    {showUpload && (
+                      <td className="p-2 text-right">
+                        {formatMs(metrics.upload_ms)}
+                      </td>
+                    )}
+                    <td className="p-2 text-right">{metrics.inference_ms}</td>
+                    <td className="p-2 text-right">
+                      {formatMs(metrics.post_processing_ms)}
+                    </td>
+                    <td className="p-2 text-right">
+                      {formatMs(metrics.insertion_ms)}
+                    </td>
+                    <td className="p-2 text-right">{metrics.total_ms}</td>
+                  </tr>
+                ))}
+              </tbody>
+            </table>
+          </div>
+        )}
+      </div>
+    </SettingContainer>
+  );
+};
@@ -1,3 +1,7 @@
 export { WordCorrectionThreshold } from "./WordCorrectionThreshold";
 export { LogDirectory } from "./LogDirectory";
+export { ExportDiagnostics } from "./ExportDiagnostics";
 export { LogLevelSelector } from "./LogLevelSelector";
+export { LogViewer } from "./LogViewer";
+export { PipelineLatency } from "./PipelineLatency";
+export { CrashReporting } from "./CrashReporting";
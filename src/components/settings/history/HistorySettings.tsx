@@ -1,14 +1,20 @@
-import React, { useState, useEffect, useCallback } from "react";
+import React, { useState, useEffect, useCallback, useRef } from "react";
 import { useTranslation } from "react-i18next";
 import { AudioPlayer } from "../../ui/AudioPlayer";
 import { Button } from "../../ui/Button";
-import { Copy, Star, Check, Trash2, FolderOpen } from "lucide-react";
+import { Copy, Star, Check, Trash2, FolderOpen, Send } from "lucide-react";
 import { convertFileSrc } from "@tauri-apps/api/core";
 import { listen } from "@tauri-apps/api/event";
 import { readFile } from "@tauri-apps/plugin-fs";
-import { commands, type HistoryEntry } from "@/bindings";
+import { toast } from "sonner";
+import {
+  commands,
+  type HistoryEntry,
+  type SendToConnector,
+} from "@/bindings";
 import { formatDateTime } from "@/utils/dateFormat";
 import { useOsType } from "@/hooks/useOsType";
+import { useSettings } from "@/hooks/useSettings";
 
 interface OpenRecordingsButtonProps {
   onClick: () => void;
@@ -36,6 +42,8 @@ export const HistorySettings: React.FC = () => {
   const osType = useOsType();
   const [historyEntries, setHistoryEntries] = useState<HistoryEntry[]>([]);
   const [loading, setLoading] = useState(true);
+  const { getSetting } = useSettings();
+  const connectors = getSetting("send_to_connectors") || [];
 
   const loadHistoryEntries = useCallback(async () => {
     try {
@@ -124,6 +132,22 @@ export const HistorySettings: React.FC = () => {
     }
   };
 
+  const sendToConnector = async (id: number, connector: SendToConnector) => {
+    const result = await commands.sendHistoryEntryToConnector(id, connector.id);
+    if (result.status === "ok") {
+      toast.success(
+        t("settings.history.sendTo.sent", { name: connector.name }),
+      );
+    } else {
+      toast.error(
+        t("settings.history.sendTo.failed", {
+          name: connector.name,
+          error: result.error,
+        }),
+      );
+    }
+  };
+
   const openRecordingsFolder = async () => {
     try {
       await commands.openRecordingsFolder();
@@ -206,6 +230,8 @@ export const HistorySettings: React.FC = () => {
                 onCopyText={() => copyToClipboard(entry.transcription_text)}
                 getAudioUrl={getAudioUrl}
                 deleteAudio={deleteAudioEntry}
+                connectors={connectors}
+                onSendTo={(connector) => sendToConnector(entry.id, connector)}
               />
             ))}
           </div>
@@ -221,6 +247,8 @@ interface HistoryEntryProps {
   onCopyText: () => void;
   getAudioUrl: (fileName: string) => Promise<string | null>;
   deleteAudio: (id: number) => Promise<void>;
+  connectors: SendToConnector[];
+  onSendTo: (connector: SendToConnector) => void;
 }
 
 const HistoryEntryComponent: React.FC<HistoryEntryProps> = ({
@@ -229,9 +257,33 @@ const HistoryEntryComponent: React.FC<HistoryEntryProps> = ({
   onCopyText,
   getAudioUrl,
   deleteAudio,
+  connectors,
+  onSendTo,
 }) => {
   const { t, i18n } = useTranslation();
   const [showCopied, setShowCopied] = useState(false);
+  const [showSendMenu, setShowSendMenu] = useState(false);
+  const sendMenuRef = useRef<HTMLDivElement>(null);
+
+  useEffect(() => {
+    if (!showSendMenu) return;
+
+    const handleClickOutside = (event: MouseEvent) => {
+      if (
+        sendMenuRef.current &&
+        !sendMenuRef.current.contains(event.target as Node)
+      ) {
+        setShowSendMenu(false);
+      }
+    };
+    document.addEventListener("mousedown", handleClickOutside);
+    return () => document.removeEventListener("mousedown", handleClickOutside);
+  }, [showSendMenu]);
+
+  const handleSendTo = (connector: SendToConnector) => {
+    setShowSendMenu(false);
+    onSendTo(connector);
+  };
 
   const handleLoadAudio = useCallback(
     () => getAudioUrl(entry.file_name),
@@ -271,6 +323,30 @@ const HistoryEntryComponent: React.FC<HistoryEntryProps> = ({
               <Copy width={16} height={16} />
             )}
           </button>
+          {connectors.length > 0 && (
+            <div className="relative" ref={sendMenuRef}>
+              <button
+                onClick={() => setShowSendMenu((open) => !open)}
+                className="p-2 rounded text-text/50 hover:text-logo-primary transition-colors cursor-pointer"
+                title={t("settings.history.sendTo.title")}
+              >
+                <Send width={16} height={16} />
+              </button>
+              {showSendMenu && (
+                <div className="absolute right-0 top-full mt-1 z-50 min-w-40 bg-background border border-mid-gray/80 rounded shadow-lg py-1">
+                  {connectors.map((connector) => (
+                    <button
+                      key={connector.id}
+                      onClick={() => handleSendTo(connector)}
+                      className="w-full px-3 py-1.5 text-sm text-start hover:bg-logo-primary/10 transition-colors cursor-pointer"
+                    >
+                      {connector.name}
+                    </button>
+                  ))}
+                </div>
+              )}
+            </div>
+          )}
           <button
             onClick={onToggleSaved}
             className={`p-2 rounded  transition-colors cursor-pointer ${
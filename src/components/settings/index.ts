@@ -28,5 +28,8 @@ export { ModelUnloadTimeoutSetting } from "./ModelUnloadTimeout";
 export { StartHidden } from "./StartHidden";
 export { HistoryLimit } from "./HistoryLimit";
 export { RecordingRetentionPeriodSelector } from "./RecordingRetentionPeriod";
+export { SendToConnectors } from "./SendToConnectors";
 export { AutostartToggle } from "./AutostartToggle";
+export { AutostartStartHidden } from "./AutostartStartHidden";
+export { UpdateChannelSelector } from "./UpdateChannelSelector";
 export { UpdateChecksToggle } from "./UpdateChecksToggle";
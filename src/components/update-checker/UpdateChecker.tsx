@@ -1,8 +1,7 @@
 import React, { useState, useEffect, useRef } from "react";
 import { useTranslation } from "react-i18next";
-import { check } from "@tauri-apps/plugin-updater";
-import { relaunch } from "@tauri-apps/plugin-process";
 import { listen } from "@tauri-apps/api/event";
+import { commands, type UpdateDownloadProgress } from "@/bindings";
 import { ProgressBar } from "../shared";
 import { useSettings } from "../../hooks/useSettings";
 
@@ -22,11 +21,10 @@ const UpdateChecker: React.FC<UpdateCheckerProps> = ({ className = "" }) => {
   const { settings, isLoading } = useSettings();
   const settingsLoaded = !isLoading && settings !== null;
   const updateChecksEnabled = settings?.update_checks_enabled ?? false;
+  const updateChannel = settings?.update_channel;
 
   const upToDateTimeoutRef = useRef<ReturnType<typeof setTimeout>>();
   const isManualCheckRef = useRef(false);
-  const downloadedBytesRef = useRef(0);
-  const contentLengthRef = useRef(0);
 
   useEffect(() => {
     // Wait for settings to load before doing anything
@@ -42,30 +40,58 @@ const UpdateChecker: React.FC<UpdateCheckerProps> = ({ className = "" }) => {
       return;
     }
 
-    checkForUpdates();
-
     // Listen for update check events
     const updateUnlisten = listen("check-for-updates", () => {
       handleManualUpdateCheck();
     });
 
+    // Background checks in the backend announce updates they find
+    const availableUnlisten = listen("update-available", () => {
+      setUpdateAvailable(true);
+      setShowUpToDate(false);
+    });
+
+    const progressUnlisten = listen<UpdateDownloadProgress>(
+      "update-download-progress",
+      (event) => {
+        const { downloaded, total } = event.payload;
+        const progress =
+          total && total > 0 ? Math.round((downloaded / total) * 100) : 0;
+        setDownloadProgress(Math.min(progress, 100));
+      },
+    );
+
     return () => {
       if (upToDateTimeoutRef.current) {
         clearTimeout(upToDateTimeoutRef.current);
       }
       updateUnlisten.then((fn) => fn());
+      availableUnlisten.then((fn) => fn());
+      progressUnlisten.then((fn) => fn());
     };
   }, [settingsLoaded, updateChecksEnabled]);
 
+  // The backend checks on startup and on a schedule; pick up what it already
+  // found, including after a channel change clears it
+  useEffect(() => {
+    if (!settingsLoaded || !updateChecksEnabled) return;
+    commands
+      .getPendingUpdate()
+      .then((update) => setUpdateAvailable(update !== null));
+  }, [settingsLoaded, updateChecksEnabled, updateChannel]);
+
   // Update checking functions
   const checkForUpdates = async () => {
     if (!updateChecksEnabled || isChecking) return;
 
     try {
       setIsChecking(true);
-      const update = await check();
+      const result = await commands.checkForUpdate();
+      if (result.status === "error") {
+        throw new Error(result.error);
+      }
 
-      if (update) {
+      if (result.data) {
         setUpdateAvailable(true);
         setShowUpToDate(false);
       } else {
@@ -100,42 +126,17 @@ const UpdateChecker: React.FC<UpdateCheckerProps> = ({ className = "" }) => {
     try {
       setIsInstalling(true);
       setDownloadProgress(0);
-      downloadedBytesRef.current = 0;
-      contentLengthRef.current = 0;
-      const update = await check();
-
-      if (!update) {
-        console.log("No update available during install attempt");
-        return;
+      // Restarts the app once installed, or opens the release page where
+      // the update can't be applied in place
+      const result = await commands.installUpdate();
+      if (result.status === "error") {
+        throw new Error(result.error);
       }
-
-      await update.downloadAndInstall((event) => {
-        switch (event.event) {
-          case "Started":
-            downloadedBytesRef.current = 0;
-            contentLengthRef.current = event.data.contentLength ?? 0;
-            break;
-          case "Progress":
-            downloadedBytesRef.current += event.data.chunkLength;
-            const progress =
-              contentLengthRef.current > 0
-                ? Math.round(
-                    (downloadedBytesRef.current / contentLengthRef.current) *
-                      100,
-                  )
-                : 0;
-            setDownloadProgress(Math.min(progress, 100));
-            break;
-        }
-      });
-      await relaunch();
     } catch (error) {
       console.error("Failed to install update:", error);
     } finally {
       setIsInstalling(false);
       setDownloadProgress(0);
-      downloadedBytesRef.current = 0;
-      contentLengthRef.current = 0;
     }
   };
 
@@ -0,0 +1,6 @@
+import type { PermissionStatus } from "@/bindings";
+
+// Anything short of an explicit grant counts as missing, so a permission the
+// user hasn't answered yet isn't mistaken for one that was granted
+export const isPermissionSatisfied = (status: PermissionStatus): boolean =>
+  status === "granted" || status === "not_required";
@@ -11,7 +11,7 @@ import { commands } from "@/bindings";
 import i18n, { syncLanguageFromSettings } from "@/i18n";
 import { getLanguageDirection } from "@/lib/utils/rtl";
 
-type OverlayState = "recording" | "transcribing";
+type OverlayState = "recording" | "transcribing" | "paste_blocked";
 
 const RecordingOverlay: React.FC = () => {
   const { t } = useTranslation();
@@ -96,6 +96,9 @@ const RecordingOverlay: React.FC = () => {
         {state === "transcribing" && (
           <div className="transcribing-text">{t("overlay.transcribing")}</div>
         )}
+        {state === "paste_blocked" && (
+          <div className="notice-text">{t("overlay.pasteBlocked")}</div>
+        )}
       </div>
 
       <div className="overlay-right">